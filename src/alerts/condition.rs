@@ -0,0 +1,601 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use eyre::{eyre, Result};
+use serde::Deserialize;
+
+use crate::models::{Change, ChangeKind};
+
+/// Fields of every kind of change, by the name rules refer to the kind with.
+const FIELDS: [(&str, &[&str]); 5] = [
+    (
+        "marks",
+        &[
+            "course",
+            "component",
+            "mark",
+            "max",
+            "percent",
+            "previous",
+            "posted",
+            "changed",
+        ],
+    ),
+    ("grade", &["course", "grade", "posted"]),
+    (
+        "attendance",
+        &["course", "percent", "previous", "dropped", "rose"],
+    ),
+    ("message", &["course", "faculty", "text"]),
+    ("spotlight", &["category", "title"]),
+];
+
+/// A condition over a change, like `attendance.percent < 76` or
+/// `marks.component == "FAT" && posted`.
+///
+/// Fields are written as `kind.field`, or just `field` for the field of whatever kind
+/// of change is checked. A condition naming kinds only matches changes of those kinds.
+/// Comparisons are `==`, `!=`, `<`, `<=`, `>` and `>=`, text is compared ignoring case,
+/// and conditions combine with `&&`, `||`, `!` and parentheses.
+///
+/// Conditions only match changes, never the data as it is. `attendance.percent < 76`
+/// matches when a fetch finds a course whose attendance changed to below 76, but not a
+/// course which was already below it and didn't change. Nothing matches on the first
+/// fetch of a semester, as there is nothing to compare it with.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Condition {
+    expression: Expression,
+    /// Kinds of changes named in the condition.
+    kinds: HashSet<String>,
+}
+
+impl TryFrom<String> for Condition {
+    type Error = eyre::Report;
+
+    fn try_from(source: String) -> Result<Condition> {
+        let tokens = tokenize(&source)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            position: 0,
+        };
+
+        let expression = parser.or()?;
+        if let Some(token) = parser.peek() {
+            return Err(eyre!("Unexpected {:?} in {}", token, source));
+        }
+
+        let mut kinds = HashSet::new();
+        expression.collect_kinds(&mut kinds);
+
+        Ok(Condition { expression, kinds })
+    }
+}
+
+impl Condition {
+    pub fn matches(&self, change: &Change) -> bool {
+        let (kind, _) = fields(&change.kind);
+        if !self.kinds.is_empty() && !self.kinds.contains(kind) {
+            return false;
+        }
+
+        self.expression.evaluate(&change.kind).is_true()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    /// A field the change doesn't have.
+    Missing,
+}
+
+impl Value {
+    fn is_true(&self) -> bool {
+        match self {
+            Value::Number(number) => *number != 0.0,
+            Value::Text(text) => !text.is_empty(),
+            Value::Bool(value) => *value,
+            Value::Missing => false,
+        }
+    }
+
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Text(a), Value::Text(b)) => Some(a.to_lowercase().cmp(&b.to_lowercase())),
+            (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Clone, Debug)]
+enum Expression {
+    Literal(Value),
+    Field { kind: Option<String>, field: String },
+    Not(Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Compare(Operator, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    fn evaluate(&self, change: &ChangeKind) -> Value {
+        match self {
+            Expression::Literal(value) => value.clone(),
+            Expression::Field { kind, field } => {
+                let (change_kind, values) = fields(change);
+                if kind.as_deref().is_some_and(|kind| kind != change_kind) {
+                    return Value::Missing;
+                }
+
+                values
+                    .into_iter()
+                    .find(|(name, _)| name == field)
+                    .map(|(_, value)| value)
+                    .unwrap_or(Value::Missing)
+            }
+            Expression::Not(expression) => Value::Bool(!expression.evaluate(change).is_true()),
+            Expression::And(a, b) => {
+                Value::Bool(a.evaluate(change).is_true() && b.evaluate(change).is_true())
+            }
+            Expression::Or(a, b) => {
+                Value::Bool(a.evaluate(change).is_true() || b.evaluate(change).is_true())
+            }
+            Expression::Compare(operator, a, b) => {
+                let ordering = match a.evaluate(change).compare(&b.evaluate(change)) {
+                    Some(ordering) => ordering,
+                    None => return Value::Bool(false),
+                };
+
+                Value::Bool(match operator {
+                    Operator::Equal => ordering == Ordering::Equal,
+                    Operator::NotEqual => ordering != Ordering::Equal,
+                    Operator::Less => ordering == Ordering::Less,
+                    Operator::LessOrEqual => ordering != Ordering::Greater,
+                    Operator::Greater => ordering == Ordering::Greater,
+                    Operator::GreaterOrEqual => ordering != Ordering::Less,
+                })
+            }
+        }
+    }
+
+    fn collect_kinds(&self, kinds: &mut HashSet<String>) {
+        match self {
+            Expression::Literal(_) => {}
+            Expression::Field { kind, .. } => kinds.extend(kind.clone()),
+            Expression::Not(expression) => expression.collect_kinds(kinds),
+            Expression::And(a, b) | Expression::Or(a, b) | Expression::Compare(_, a, b) => {
+                a.collect_kinds(kinds);
+                b.collect_kinds(kinds);
+            }
+        }
+    }
+}
+
+/// Kind of the change and the values of its fields.
+fn fields(change: &ChangeKind) -> (&'static str, Vec<(&'static str, Value)>) {
+    let text = |text: &String| Value::Text(text.clone());
+    let percent =
+        |mark: f64, max: f64| Value::Number(if max > 0.0 { mark / max * 100.0 } else { 0.0 });
+
+    match change {
+        ChangeKind::MarksPosted {
+            course_code,
+            component,
+            scored_mark,
+            max_mark,
+        } => (
+            "marks",
+            vec![
+                ("course", text(course_code)),
+                ("component", text(component)),
+                ("mark", Value::Number(*scored_mark)),
+                ("max", Value::Number(*max_mark)),
+                ("percent", percent(*scored_mark, *max_mark)),
+                ("posted", Value::Bool(true)),
+                ("changed", Value::Bool(false)),
+            ],
+        ),
+        ChangeKind::MarksChanged {
+            course_code,
+            component,
+            previous_mark,
+            scored_mark,
+            max_mark,
+        } => (
+            "marks",
+            vec![
+                ("course", text(course_code)),
+                ("component", text(component)),
+                ("mark", Value::Number(*scored_mark)),
+                ("max", Value::Number(*max_mark)),
+                ("percent", percent(*scored_mark, *max_mark)),
+                ("previous", Value::Number(*previous_mark)),
+                ("posted", Value::Bool(false)),
+                ("changed", Value::Bool(true)),
+            ],
+        ),
+        ChangeKind::GradePosted { course_code, grade } => (
+            "grade",
+            vec![
+                ("course", text(course_code)),
+                ("grade", text(grade)),
+                ("posted", Value::Bool(true)),
+            ],
+        ),
+        ChangeKind::AttendanceChanged {
+            course_code,
+            previous_percentage,
+            percentage,
+        } => (
+            "attendance",
+            vec![
+                ("course", text(course_code)),
+                ("percent", Value::Number(*percentage)),
+                ("previous", Value::Number(*previous_percentage)),
+                ("dropped", Value::Bool(percentage < previous_percentage)),
+                ("rose", Value::Bool(percentage > previous_percentage)),
+            ],
+        ),
+        ChangeKind::NewMessage {
+            course,
+            faculty,
+            message,
+        } => (
+            "message",
+            vec![
+                ("course", text(course)),
+                ("faculty", text(faculty)),
+                ("text", text(message)),
+            ],
+        ),
+        ChangeKind::NewSpotlight { category, title } => (
+            "spotlight",
+            vec![("category", text(category)), ("title", text(title))],
+        ),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Identifier(String),
+    Operator(Operator),
+    And,
+    Or,
+    Not,
+    OpenParenthesis,
+    CloseParenthesis,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = source.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if ch == '"' || ch == '\'' {
+            chars.next();
+            let mut text = String::new();
+            loop {
+                match chars.next() {
+                    Some(next) if next == ch => break,
+                    Some(next) => text.push(next),
+                    None => return Err(eyre!("Unterminated text in {}", source)),
+                }
+            }
+            tokens.push(Token::Text(text));
+            continue;
+        }
+
+        if ch.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&next) = chars.peek() {
+                if !next.is_ascii_digit() && next != '.' {
+                    break;
+                }
+                number.push(next);
+                chars.next();
+            }
+            let number = number
+                .parse()
+                .map_err(|_| eyre!("Invalid number {} in {}", number, source))?;
+            tokens.push(Token::Number(number));
+            continue;
+        }
+
+        if ch.is_alphabetic() || ch == '_' {
+            let mut identifier = String::new();
+            while let Some(&next) = chars.peek() {
+                if !next.is_alphanumeric() && next != '_' && next != '.' {
+                    break;
+                }
+                identifier.push(next);
+                chars.next();
+            }
+            tokens.push(Token::Identifier(identifier));
+            continue;
+        }
+
+        chars.next();
+        let followed_by = |chars: &mut std::iter::Peekable<std::str::Chars>, expected| {
+            let matches = chars.peek() == Some(&expected);
+            if matches {
+                chars.next();
+            }
+            matches
+        };
+
+        let token = match ch {
+            '(' => Token::OpenParenthesis,
+            ')' => Token::CloseParenthesis,
+            '&' if followed_by(&mut chars, '&') => Token::And,
+            '|' if followed_by(&mut chars, '|') => Token::Or,
+            '=' if followed_by(&mut chars, '=') => Token::Operator(Operator::Equal),
+            '!' if followed_by(&mut chars, '=') => Token::Operator(Operator::NotEqual),
+            '!' => Token::Not,
+            '<' if followed_by(&mut chars, '=') => Token::Operator(Operator::LessOrEqual),
+            '<' => Token::Operator(Operator::Less),
+            '>' if followed_by(&mut chars, '=') => Token::Operator(Operator::GreaterOrEqual),
+            '>' => Token::Operator(Operator::Greater),
+            _ => return Err(eyre!("Unexpected {} in {}", ch, source)),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser, with `||` binding looser than `&&`, and `&&` looser than
+/// comparisons.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn or(&mut self) -> Result<Expression> {
+        let mut expression = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            expression = Expression::Or(Box::new(expression), Box::new(self.and()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn and(&mut self) -> Result<Expression> {
+        let mut expression = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            expression = Expression::And(Box::new(expression), Box::new(self.unary()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn unary(&mut self) -> Result<Expression> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            return Ok(Expression::Not(Box::new(self.unary()?)));
+        }
+
+        let expression = self.primary()?;
+        match self.peek() {
+            Some(Token::Operator(operator)) => {
+                self.next();
+                Ok(Expression::Compare(
+                    *operator,
+                    Box::new(expression),
+                    Box::new(self.primary()?),
+                ))
+            }
+            _ => Ok(expression),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expression> {
+        match self.next() {
+            Some(Token::Number(number)) => Ok(Expression::Literal(Value::Number(*number))),
+            Some(Token::Text(text)) => Ok(Expression::Literal(Value::Text(text.clone()))),
+            Some(Token::Identifier(identifier)) => field(identifier),
+            Some(Token::OpenParenthesis) => {
+                let expression = self.or()?;
+                match self.next() {
+                    Some(Token::CloseParenthesis) => Ok(expression),
+                    _ => Err(eyre!("Missing a closing parenthesis.")),
+                }
+            }
+            Some(token) => Err(eyre!("Unexpected {:?}", token)),
+            None => Err(eyre!("The condition ended early.")),
+        }
+    }
+}
+
+/// Parses `true`, `false`, `field` or `kind.field`, checking that the field exists.
+fn field(identifier: &str) -> Result<Expression> {
+    match identifier {
+        "true" => return Ok(Expression::Literal(Value::Bool(true))),
+        "false" => return Ok(Expression::Literal(Value::Bool(false))),
+        _ => {}
+    }
+
+    let (kind, field) = match identifier.split_once('.') {
+        Some((kind, field)) => (Some(kind), field),
+        None => (None, identifier),
+    };
+
+    let known = match kind {
+        Some(kind) => {
+            let (_, fields) = FIELDS
+                .iter()
+                .find(|(name, _)| *name == kind)
+                .ok_or_else(|| {
+                    eyre!(
+                        "Unknown kind {}, expected one of {}",
+                        kind,
+                        FIELDS
+                            .iter()
+                            .map(|(name, _)| *name)
+                            .collect::<Vec<&str>>()
+                            .join(", ")
+                    )
+                })?;
+            fields.contains(&field)
+        }
+        None => FIELDS.iter().any(|(_, fields)| fields.contains(&field)),
+    };
+    if !known {
+        return Err(eyre!("Unknown field {}", identifier));
+    }
+
+    Ok(Expression::Field {
+        kind: kind.map(str::to_string),
+        field: field.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::*;
+
+    fn condition(source: &str) -> Condition {
+        Condition::try_from(source.to_string()).unwrap()
+    }
+
+    fn change(kind: ChangeKind) -> Change {
+        Change {
+            detected_at: Local::now(),
+            kind,
+        }
+    }
+
+    fn attendance(course_code: &str, previous_percentage: f64, percentage: f64) -> Change {
+        change(ChangeKind::AttendanceChanged {
+            course_code: course_code.to_string(),
+            previous_percentage,
+            percentage,
+        })
+    }
+
+    fn marks_posted(component: &str, scored_mark: f64, max_mark: f64) -> Change {
+        change(ChangeKind::MarksPosted {
+            course_code: "CSE3001".to_string(),
+            component: component.to_string(),
+            scored_mark,
+            max_mark,
+        })
+    }
+
+    fn message(course: &str) -> Change {
+        change(ChangeKind::NewMessage {
+            course: course.to_string(),
+            faculty: "Faculty".to_string(),
+            message: "Class moved".to_string(),
+        })
+    }
+
+    #[test]
+    fn compares_attendance_percent() {
+        let condition = condition("attendance.percent < 76");
+
+        assert!(condition.matches(&attendance("CSE3001", 80.0, 75.5)));
+        assert!(!condition.matches(&attendance("CSE3001", 70.0, 76.0)));
+    }
+
+    #[test]
+    fn combines_marks_component_with_posted() {
+        let condition = condition(r#"marks.component == "FAT" && posted"#);
+
+        assert!(condition.matches(&marks_posted("FAT", 40.0, 100.0)));
+        assert!(condition.matches(&marks_posted("fat", 40.0, 100.0)));
+        assert!(!condition.matches(&marks_posted("CAT1", 40.0, 50.0)));
+        assert!(!condition.matches(&change(ChangeKind::MarksChanged {
+            course_code: "CSE3001".to_string(),
+            component: "FAT".to_string(),
+            previous_mark: 38.0,
+            scored_mark: 40.0,
+            max_mark: 100.0,
+        })));
+    }
+
+    #[test]
+    fn compares_message_course() {
+        let condition = condition(r#"message.course == "CSE3001""#);
+
+        assert!(condition.matches(&message("CSE3001")));
+        assert!(!condition.matches(&message("MAT2002")));
+    }
+
+    #[test]
+    fn binds_not_tighter_than_and_and_and_tighter_than_or() {
+        // Read as `(!posted && false) || true`, not `!(posted && (false || true))`.
+        assert!(condition("!posted && false || true").matches(&marks_posted("FAT", 1.0, 2.0)));
+        // Read as `!(percent < 76)`.
+        assert!(condition("!percent < 76").matches(&attendance("CSE3001", 70.0, 80.0)));
+        // Read as `true || (false && false)`.
+        assert!(condition("true || false && false").matches(&message("CSE3001")));
+        assert!(!condition("(true || false) && false").matches(&message("CSE3001")));
+    }
+
+    #[test]
+    fn rejects_unknown_kinds_and_fields() {
+        let error = |source: &str| {
+            Condition::try_from(source.to_string())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(error("exam.date == 1").starts_with("Unknown kind exam"));
+        assert_eq!(
+            error("attendance.grade == \"S\""),
+            "Unknown field attendance.grade"
+        );
+        assert_eq!(error("score > 1"), "Unknown field score");
+    }
+
+    #[test]
+    fn only_matches_the_kinds_named() {
+        let condition = condition("attendance.dropped || course == \"CSE3001\"");
+
+        assert!(condition.matches(&attendance("MAT2002", 80.0, 75.0)));
+        assert!(condition.matches(&attendance("CSE3001", 70.0, 75.0)));
+        assert!(!condition.matches(&message("CSE3001")));
+        assert!(!condition.matches(&marks_posted("FAT", 40.0, 100.0)));
+
+        // Without a kind, changes of every kind having the field are checked.
+        let any_kind = self::condition("course == \"CSE3001\"");
+        assert!(any_kind.matches(&message("CSE3001")));
+        assert!(any_kind.matches(&marks_posted("FAT", 40.0, 100.0)));
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Stdio,
+};
+
+use eyre::{eyre, Result};
+use serde::Deserialize;
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use crate::models::Change;
+
+pub use condition::Condition;
+
+mod condition;
+
+/// A rule from the `alerts` of the config, checked against every change detected after
+/// a fetch. See `Condition` for the syntax of `when` and what it matches.
+///
+/// ```toml
+/// [[alerts]]
+/// name = "Attendance"
+/// when = "attendance.percent < 76"
+/// bell = true
+/// append = "/home/me/alerts.log"
+/// exec = "notify-send VTOP \"$(jq -r .course_code)\""
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+pub struct AlertRule {
+    /// Shown before the change in the notification and the appended line.
+    pub name: Option<String>,
+    pub when: Condition,
+    /// Shows the change in the footer.
+    #[serde(default = "default_notify")]
+    pub notify: bool,
+    /// Rings the terminal bell.
+    #[serde(default)]
+    pub bell: bool,
+    /// File to append the change to.
+    pub append: Option<PathBuf>,
+    /// Shell command to run with the change as JSON on stdin.
+    pub exec: Option<String>,
+}
+
+fn default_notify() -> bool {
+    true
+}
+
+/// A change which matched a rule.
+pub struct Alert {
+    pub rule: AlertRule,
+    pub change: Change,
+}
+
+impl Alert {
+    pub fn message(&self) -> String {
+        match &self.rule.name {
+            Some(name) => format!("{}: {}", name, self.change.kind),
+            None => self.change.kind.to_string(),
+        }
+    }
+
+    /// Runs the bell, append and exec actions of the rule. Notifications need the app
+    /// state, so they are left to the caller.
+    pub async fn run_actions(&self) -> Result<()> {
+        if self.rule.bell {
+            let mut stdout = io::stdout();
+            stdout.write_all(b"\x07")?;
+            stdout.flush()?;
+        }
+
+        if let Some(path) = &self.rule.append {
+            append_line(
+                path,
+                &format!(
+                    "{} {}",
+                    self.change.detected_at.format("%Y-%m-%d %H:%M:%S"),
+                    self.message()
+                ),
+            )
+            .map_err(|e| eyre!("Failed to write to {}: {}", path.display(), e))?;
+        }
+
+        if let Some(command) = &self.rule.exec {
+            run_hook(command, &self.change)
+                .await
+                .map_err(|e| eyre!("Failed to run {}: {}", command, e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Every pair of a rule and a change matching it, in the order of the changes.
+pub fn matching_alerts(rules: &[AlertRule], changes: &[Change]) -> Vec<Alert> {
+    changes
+        .iter()
+        .flat_map(|change| {
+            rules
+                .iter()
+                .filter(|rule| rule.when.matches(change))
+                .map(|rule| Alert {
+                    rule: rule.clone(),
+                    change: change.clone(),
+                })
+        })
+        .collect()
+}
+
+pub fn append_line(path: &Path, line: &str) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+
+    Ok(())
+}
+
+/// Runs the command through the shell with the change as JSON on stdin. Its output is
+/// discarded, as it would be drawn over the TUI.
+pub async fn run_hook(command: &str, change: &Change) -> Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&serde_json::to_vec(change)?).await?;
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(eyre!("The command exited with {}.", status));
+    }
+
+    Ok(())
+}
@@ -6,10 +6,13 @@ use crossterm::{
 };
 
 use crate::{
+    config::Config,
     input::Key,
     network::NetworkEvent,
-    pages::{Page, PageBlock},
+    pages::{Page, PageAction, PageBlock},
+    slots,
     state::{AppState, TabState, Window},
+    storage,
     util::{MAXIMUM_TABS, NOTIFICATION_SEPERATOR},
 };
 
@@ -37,18 +40,36 @@ pub struct App {
     pub key_processed: Option<bool>,
     pub exit_app: bool,
     pub mouse_capture: bool,
+    pub config: Config,
 }
 
 impl App {
     pub fn new(network_event_tx: tokio::sync::mpsc::Sender<NetworkEvent>) -> App {
         let is_loading = false;
+        let mut state = AppState::default();
+
+        let config = Config::load().unwrap_or_else(|e| {
+            state.dispatch_notification(e.to_string());
+            Config::default()
+        });
+
+        if let Err(e) = slots::set_campus(&config.campus, &config.campus_profiles) {
+            state.dispatch_notification(e.to_string());
+        }
+
+        match storage::load() {
+            Ok(data) => state.data = data,
+            Err(e) => state.dispatch_notification(e.to_string()),
+        }
+
         App {
             network_event_tx,
             is_loading,
-            state: AppState::default(),
+            state,
             key_processed: None,
             exit_app: false,
             mouse_capture: true,
+            config,
         }
     }
 
@@ -56,7 +77,7 @@ impl App {
         if let Key::Ctrl(ch) = key {
             match ch {
                 'C' | 'c' => self.exit_app = true,
-                'T' | 't' => self.new_tab(),
+                'T' | 't' => self.new_tab().await,
                 'W' | 'w' => self.delete_tab(),
                 'E' | 'e' => self.toggle_mouse_capture(),
                 _ => (),
@@ -72,7 +93,7 @@ impl App {
             if self.state.selected_tab == 0 {
                 self.state.selected_tab = self.state.tabs.len() - 1;
             } else {
-                self.state.selected_tab = self.state.selected_tab - 1;
+                self.state.selected_tab -= 1;
             }
             self.key_processed = Some(true);
         }
@@ -96,7 +117,7 @@ impl App {
         } else if let Key::Enter = key {
             tab_state.active_window = tab_state.hovered_window.clone();
 
-            // if PageWindow is selected, it's first block should be hovered 
+            // if PageWindow is selected, it's first block should be hovered
             if let Some(Window::PageWindow) = tab_state.active_window {
                 self.state.tabs[self.state.selected_tab]
                     .state
@@ -108,7 +129,7 @@ impl App {
         }
     }
 
-    pub fn handle_sidebar_input(&mut self, key: Key) {
+    pub async fn handle_sidebar_input(&mut self, key: Key) {
         let current_sidebar_hover = self.state.tabs[self.state.selected_tab].state.sidebar_hover;
 
         if let Key::Tab | Key::Right | Key::Down = key {
@@ -139,8 +160,7 @@ impl App {
                     .nth(self.state.tabs[self.state.selected_tab].state.sidebar_hover)
                     .unwrap();
 
-                self.state.tabs[self.state.selected_tab].state.page_block =
-                    PageBlock::new(new_page.clone());
+                self.open_page(*new_page).await;
             }
         } else if let Key::Esc = key {
             // On Esc key, sidebar is no longer selected and sidebar_hover returns to the position
@@ -165,7 +185,14 @@ impl App {
         self.key_processed = Some(false);
         self.state.dispatch_notification(key.to_string()); // DEBUG
 
-        self.handle_global_keys(key).await;
+        // Keys bound globally are typed into inputs instead while one is being edited,
+        // except for Ctrl+C.
+        let tab_state = &self.state.tabs[self.state.selected_tab].state;
+        let is_editing_text = matches!(tab_state.active_window, Some(Window::PageWindow))
+            && tab_state.page_block.block.is_editing_text();
+        if !is_editing_text || key == Key::Ctrl('c') {
+            self.handle_global_keys(key).await;
+        }
 
         let tab_state = &mut self.state.tabs[self.state.selected_tab].state;
 
@@ -173,7 +200,7 @@ impl App {
             self.handle_window_hover(key);
         } else {
             match tab_state.active_window.as_ref().unwrap() {
-                Window::SidebarWindow => self.handle_sidebar_input(key),
+                Window::SidebarWindow => self.handle_sidebar_input(key).await,
                 Window::PageWindow => {
                     // if none of the blocks inside PageWindow is selected, Esc causes the
                     // de-selection of PageWindow, else Esc key is transfered to the page
@@ -182,15 +209,23 @@ impl App {
                     let mut flag = false;
 
                     if let Key::Esc = key {
-                        if tab_state.page_block.block.has_selected_child() == false {
+                        if !tab_state.page_block.block.has_selected_child() {
                             tab_state.page_block.block.deselect();
                             tab_state.active_window = None;
                             flag = true;
                         }
                     }
 
-                    if flag == false {
-                        tab_state.page_block.block.handle_input(key);
+                    if !flag {
+                        let previous_global_semester = self.state.data.global_semester_id.clone();
+
+                        let page_actions =
+                            tab_state.page_block.handle_input(key, &mut self.state.data);
+                        self.handle_page_actions(page_actions).await;
+
+                        if self.state.data.global_semester_id != previous_global_semester {
+                            self.on_global_semester_change().await;
+                        }
                     }
                 }
             }
@@ -199,8 +234,27 @@ impl App {
         self.key_processed = None;
     }
 
+    /// Pastes the text into the input being edited in the page, if any.
+    pub fn paste(&mut self, text: &str) {
+        let tab_state = &mut self.state.tabs[self.state.selected_tab].state;
+
+        if let Some(Window::PageWindow) = tab_state.active_window {
+            tab_state.page_block.block.paste(text);
+        }
+    }
+
+    /// Activates the button clicked in the page, if any.
+    pub async fn click(&mut self, column: u16, row: u16) {
+        let tab_state = &mut self.state.tabs[self.state.selected_tab].state;
+
+        let page_actions = tab_state
+            .page_block
+            .handle_click(column, row, &mut self.state.data);
+        self.handle_page_actions(page_actions).await;
+    }
+
     pub async fn update_on_tick(&mut self) {
-        if self.state.notifications.len() > 0 {
+        if !self.state.notifications.is_empty() {
             let notification_length = self.state.notifications[self.state.notifications.len() - 1]
                 .text
                 .len();
@@ -222,16 +276,92 @@ impl App {
         }
     }
 
+    pub async fn dispatch_all(&mut self, actions: Vec<NetworkEvent>) {
+        for action in actions {
+            self.dispatch(action).await;
+        }
+    }
+
+    /// Opens a page in the selected tab and dispatches the network events it needs.
+    pub async fn open_page(&mut self, page: Page) {
+        let mut page_block = PageBlock::new(page);
+        let network_events = page_block.on_open(&self.state.data);
+
+        let tab_state = &mut self.state.tabs[self.state.selected_tab].state;
+        tab_state.page_block = page_block;
+        tab_state.sidebar_hover = Page::iterator()
+            .position(|p| p.to_string() == page.to_string())
+            .unwrap();
+
+        self.dispatch_all(network_events).await;
+    }
+
+    async fn handle_page_actions(&mut self, page_actions: Vec<PageAction>) {
+        for page_action in page_actions {
+            match page_action {
+                PageAction::Network(network_event) => self.dispatch(network_event).await,
+                PageAction::OpenPage(page) => {
+                    self.open_page(page).await;
+
+                    // The opened page takes over the PageWindow, with its first block hovered.
+                    let tab_state = &mut self.state.tabs[self.state.selected_tab].state;
+                    tab_state.active_window = Some(Window::PageWindow);
+                    tab_state.hovered_window = Some(Window::PageWindow);
+                    tab_state.page_block.block.hover_first_block();
+                }
+            }
+        }
+    }
+
+    /// Dispatches the network events needed by the pages opened at launch, after the
+    /// semesters shared by every page.
+    pub async fn on_first_render(&mut self) {
+        self.dispatch(NetworkEvent::GetSemesters).await;
+
+        let network_events = self.state.tabs[self.state.selected_tab]
+            .state
+            .page_block
+            .on_open(&self.state.data);
+
+        self.dispatch_all(network_events).await;
+    }
+
+    /// Updates the pages of the other tabs following the global semester. The selected
+    /// tab's page fetches its own data.
+    async fn on_global_semester_change(&mut self) {
+        self.refresh_pages();
+
+        let mut network_events = vec![];
+        for (index, tab) in self.state.tabs.iter().enumerate() {
+            if index != self.state.selected_tab {
+                network_events.extend(tab.state.page_block.missing_data(&self.state.data));
+            }
+        }
+
+        self.dispatch_all(network_events).await;
+    }
+
+    /// Updates the pages of every tab with the latest data fetched from VTOP.
+    pub fn refresh_pages(&mut self) {
+        for tab in self.state.tabs.iter_mut() {
+            tab.state.page_block.refresh(&self.state.data);
+        }
+    }
+
     pub fn loaded(&mut self) {
         self.is_loading = false;
     }
 
-    pub fn new_tab(&mut self) {
+    pub async fn new_tab(&mut self) {
         if self.state.tabs.len() < MAXIMUM_TABS {
             let tabs_len = self.state.tabs.len();
             self.state.selected_tab = tabs_len;
 
-            self.state.tabs.push(Tab::new("New Tab".to_string()));
+            let mut tab = Tab::new("New Tab".to_string());
+            let network_events = tab.state.page_block.on_open(&self.state.data);
+
+            self.state.tabs.push(tab);
+            self.dispatch_all(network_events).await;
         } else {
             self.state
                 .dispatch_notification("Maximum of 10 tabs are allowed!".to_string());
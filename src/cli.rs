@@ -0,0 +1,622 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use chrono::{Datelike, Local, NaiveTime};
+use clap::{
+    builder::{PossibleValue, PossibleValuesParser},
+    Args, CommandFactory, Parser, Subcommand,
+};
+use clap_complete::Shell;
+use eyre::{eyre, Result};
+use serde_json::Value;
+
+use crate::{
+    app::App,
+    export::{self, DataTable, ExportFormat, Table},
+    models::{DataKind, VtopData},
+    network::{NetworkEvent, NetworkHandler},
+    util::{DEFAULT_WATCH_INTERVAL_MINUTES, PROJECT_DIRECTORY_NAME, VTOP_COOKIE_VARIABLE},
+    watch::{self, WatchPage},
+};
+
+/// Command line arguments. Without a subcommand the TUI is started.
+#[derive(Parser)]
+#[command(name = "vtop-cli", version, about = "A terminal client for VTOP.")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// Cookie of a VTOP session logged in from a browser. Hidden since arguments show up
+    /// in the process list, but still read from the VTOP_COOKIE environment variable.
+    #[arg(
+        long,
+        global = true,
+        env = VTOP_COOKIE_VARIABLE,
+        hide = true,
+        conflicts_with = "cookie_stdin"
+    )]
+    pub cookie: Option<String>,
+    /// Reads the cookie of a VTOP session logged in from a browser, like
+    /// `JSESSIONID=...; SERVERID=...`, from the first line of stdin. The cookie can also
+    /// be set in the VTOP_COOKIE environment variable.
+    #[arg(long, global = true)]
+    pub cookie_stdin: bool,
+}
+
+impl Cli {
+    /// The cookie from stdin if `--cookie-stdin` is given, or from the environment.
+    pub fn cookie(&self) -> Result<Option<String>> {
+        if !self.cookie_stdin {
+            return Ok(self.cookie.clone());
+        }
+
+        let mut line = String::new();
+        io::stdin().read_line(&mut line)?;
+
+        match line.trim() {
+            "" => Err(eyre!("No cookie on stdin.")),
+            cookie => Ok(Some(cookie.to_string())),
+        }
+    }
+}
+
+/// Subcommands which print to stdout without starting the TUI.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Prints the attendance of every course.
+    Attendance(TableArgs),
+    /// Prints the marks of every course, one row per mark component.
+    Marks(TableArgs),
+    /// Prints the grades of every course.
+    Grades(TableArgs),
+    /// Prints the exam schedule.
+    Exams(TableArgs),
+    /// Prints the time table.
+    Timetable {
+        #[command(flatten)]
+        args: TableArgs,
+        /// Only prints today's classes, following the academic calendar.
+        #[arg(long)]
+        today: bool,
+    },
+    /// Writes the time table as an iCalendar (.ics) file.
+    Ical {
+        #[command(flatten)]
+        semester: SemesterArg,
+        /// File to write to instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Syncs the time table and exam schedule to the CalDAV collection in the config.
+    CaldavSync {
+        #[command(flatten)]
+        semester: SemesterArg,
+    },
+    /// Keeps refreshing pages and reports what changed on them until interrupted.
+    Watch(WatchArgs),
+    /// Prints the completion script of a shell. Semesters and course codes fetched so
+    /// far are completed too, so the script is worth regenerating once in a while.
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Prints the man page in roff.
+    Man,
+}
+
+#[derive(Args)]
+pub struct SemesterArg {
+    /// Id or name of the semester, the current semester by default.
+    #[arg(short, long)]
+    pub semester: Option<String>,
+}
+
+#[derive(Args)]
+pub struct TableArgs {
+    #[command(flatten)]
+    pub semester: SemesterArg,
+    /// Format to print the table in, aligned plain text by default.
+    #[arg(short, long, value_enum, conflicts_with = "json")]
+    pub format: Option<ExportFormat>,
+    /// Same as `--format json`.
+    #[arg(long)]
+    pub json: bool,
+    /// Only prints the rows of the course with this code.
+    #[arg(short, long)]
+    pub course: Option<String>,
+    /// File to write to instead of stdout.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    pub semester: SemesterArg,
+    /// Pages to refresh, all of them by default.
+    #[arg(short, long = "page", value_enum)]
+    pub pages: Vec<WatchPage>,
+    /// Minutes between refreshes. Failed refreshes double it for the next one.
+    #[arg(short, long, default_value_t = DEFAULT_WATCH_INTERVAL_MINUTES)]
+    pub interval: u64,
+    /// File to append the changes to.
+    #[arg(short, long)]
+    pub log: Option<PathBuf>,
+    /// Shell command to run for every change, with the change as JSON on stdin.
+    #[arg(short, long)]
+    pub exec: Option<String>,
+}
+
+/// Runs the subcommand with the same network handler and cache as the TUI, logged in
+/// with the cookie if one is given. When VTOP can't be reached, the cached data is
+/// printed instead with a warning. Notifications, like same day exam alerts, are
+/// printed to stderr.
+pub async fn run(command: Command, cookie: Option<String>) -> Result<()> {
+    let (network_event_tx, _network_event_rx) = tokio::sync::mpsc::channel::<NetworkEvent>(100);
+
+    let app = Arc::new(tokio::sync::Mutex::new(App::new(network_event_tx)));
+    let mut network_handler = NetworkHandler::new(&app);
+
+    let result = match log_in(&mut network_handler, &app, &command, cookie).await {
+        Ok(()) => run_command(&mut network_handler, &app, command).await,
+        Err(e) => Err(e),
+    };
+
+    for notification in app.lock().await.state.notifications.iter() {
+        eprintln!("{}", notification.text);
+    }
+
+    result
+}
+
+/// Logs in with the cookie for the subcommands fetching from VTOP. Without a session
+/// they can only print the cached data, so they fail if nothing is cached yet, and
+/// watching fails outright since it has nothing to refresh.
+async fn log_in(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    command: &Command,
+    cookie: Option<String>,
+) -> Result<()> {
+    if let Command::Completions { .. } | Command::Man = command {
+        return Ok(());
+    }
+
+    let error = match cookie {
+        Some(cookie) => match network_handler
+            .run_network_event(NetworkEvent::LogIn { cookie })
+            .await
+        {
+            Ok(()) => return Ok(()),
+            // Some errors end in a period and some don't, and the cache fallback adds a
+            // sentence after this one.
+            Err(e) => eyre!(
+                "Failed to log in to VTOP: {}.",
+                e.to_string().trim_end_matches('.')
+            ),
+        },
+        None => eyre!(
+            "Not logged in to VTOP. Pass the cookie of a VTOP session logged in from a \
+             browser in the VTOP_COOKIE environment variable or with --cookie-stdin."
+        ),
+    };
+
+    let is_cached = app
+        .lock()
+        .await
+        .state
+        .data
+        .fetched_at(DataKind::Semesters, "")
+        .is_some();
+
+    match command {
+        Command::Watch(_) => Err(error),
+        _ if !is_cached => Err(eyre!("{} Nothing is cached yet to show instead.", error)),
+        _ => {
+            eprintln!("{}", error);
+            Ok(())
+        }
+    }
+}
+
+async fn run_command(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    command: Command,
+) -> Result<()> {
+    let (table, args) = match command {
+        Command::Attendance(args) => (DataTable::Attendance, args),
+        Command::Marks(args) => (DataTable::Marks, args),
+        Command::Grades(args) => (DataTable::Grades, args),
+        Command::Exams(args) => (DataTable::ExamSchedule, args),
+        Command::Timetable { args, today } if today => {
+            let semester_id = resolve_semester(network_handler, app, &args.semester).await?;
+            let table = today_table(network_handler, app, semester_id).await?;
+            return write_output(table, args);
+        }
+        Command::Timetable { args, .. } => (DataTable::Timetable, args),
+        Command::Ical { semester, output } => {
+            let semester_id = resolve_semester(network_handler, app, &semester).await?;
+            let calendar = timetable_calendar(network_handler, app, semester_id).await?;
+            return print_or_write(calendar.into_bytes(), output);
+        }
+        Command::CaldavSync { semester } => {
+            let semester_id = resolve_semester(network_handler, app, &semester).await?;
+            return network_handler
+                .run_network_event(NetworkEvent::SyncCalendar {
+                    semester_id: Some(semester_id),
+                })
+                .await;
+        }
+        Command::Watch(args) => return watch::run(network_handler, app, args).await,
+        Command::Completions { shell } => {
+            let values = CompletionValues::from_data(&app.lock().await.state.data);
+            let mut command = command_with_completion_values(values);
+            let mut script = vec![];
+            clap_complete::generate(shell, &mut command, PROJECT_DIRECTORY_NAME, &mut script);
+            return print_or_write(script, None);
+        }
+        Command::Man => {
+            let mut page = vec![];
+            clap_mangen::Man::new(Cli::command()).render(&mut page)?;
+            return print_or_write(page, None);
+        }
+    };
+
+    let semester_id = resolve_semester(network_handler, app, &args.semester).await?;
+    let semester = Some(semester_id.clone());
+    fetch(
+        network_handler,
+        app,
+        match table {
+            DataTable::Timetable => NetworkEvent::GetTimetable {
+                semester_id: semester,
+            },
+            DataTable::Attendance => NetworkEvent::GetAttendance {
+                semester_id: semester,
+            },
+            DataTable::Marks => NetworkEvent::GetMarks {
+                semester_id: semester,
+            },
+            DataTable::Grades => NetworkEvent::GetGrades {
+                semester_id: semester,
+            },
+            DataTable::ExamSchedule => NetworkEvent::GetExamSchedule {
+                semester_id: semester,
+            },
+        },
+        table.kind(),
+        &semester_id,
+    )
+    .await?;
+
+    let table = table
+        .table(&app.lock().await.state.data, &semester_id)
+        .ok_or_else(|| eyre!("No {} found.", table.name().to_lowercase()))?;
+
+    write_output(table, args)
+}
+
+/// Runs the event, falling back to the cached data of the kind with the given key if
+/// the event fails.
+async fn fetch(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    network_event: NetworkEvent,
+    kind: DataKind,
+    key: &str,
+) -> Result<()> {
+    let error = match network_handler.run_network_event(network_event).await {
+        Ok(()) => return Ok(()),
+        Err(error) => error,
+    };
+
+    match app.lock().await.state.data.fetched_at(kind, key) {
+        Some(fetched_at) => {
+            eprintln!(
+                "{} Showing the data cached at {}.",
+                error,
+                fetched_at.format("%d-%b-%Y %H:%M")
+            );
+            Ok(())
+        }
+        None => Err(error),
+    }
+}
+
+/// Fetches the semesters and finds the one with the given id or name, or the current
+/// semester if none is given. Names are matched loosely, so `fall 2024` finds
+/// `Fall Semester 2024-25`.
+pub(crate) async fn resolve_semester(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    semester: &SemesterArg,
+) -> Result<String> {
+    fetch(
+        network_handler,
+        app,
+        NetworkEvent::GetSemesters,
+        DataKind::Semesters,
+        "",
+    )
+    .await?;
+
+    let app = app.lock().await;
+    let semesters = &app.state.data.semesters;
+
+    let query = match &semester.semester {
+        Some(query) => query.trim().to_lowercase(),
+        None => {
+            return app
+                .state
+                .data
+                .current_semester()
+                .map(|semester| semester.id.clone())
+                .ok_or_else(|| eyre!("No semesters found on VTOP."))
+        }
+    };
+
+    semesters
+        .iter()
+        .find(|semester| {
+            semester.id.to_lowercase() == query || semester.name.to_lowercase() == query
+        })
+        .or_else(|| {
+            semesters.iter().find(|semester| {
+                let name = semester.name.to_lowercase();
+                query.split_whitespace().all(|word| name.contains(word))
+            })
+        })
+        .map(|semester| semester.id.clone())
+        .ok_or_else(|| {
+            eyre!(
+                "No semester matches {}. The semesters on VTOP are: {}.",
+                query,
+                semesters
+                    .iter()
+                    .map(|semester| format!("{} ({})", semester.name, semester.id))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Today's classes by start time. Holidays and day orders are followed if the academic
+/// calendar can be fetched.
+async fn today_table(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    semester_id: String,
+) -> Result<Table> {
+    fetch(
+        network_handler,
+        app,
+        NetworkEvent::GetTimetable {
+            semester_id: Some(semester_id.clone()),
+        },
+        DataKind::Timetable,
+        &semester_id,
+    )
+    .await?;
+    let calendar_result = fetch(
+        network_handler,
+        app,
+        NetworkEvent::GetAcademicCalendar {
+            semester_id: Some(semester_id.clone()),
+        },
+        DataKind::AcademicCalendar,
+        &semester_id,
+    )
+    .await;
+
+    let app = app.lock().await;
+    let data = &app.state.data;
+
+    let today = Local::now().date_naive();
+    let weekday = match (calendar_result, data.academic_calendar.get(&semester_id)) {
+        (Ok(()), Some(calendar)) => calendar.day_order(today),
+        _ => Some(today.weekday()),
+    };
+
+    let mut classes: Vec<(NaiveTime, Vec<Value>)> = vec![];
+    for entry in data.timetable.get(&semester_id).into_iter().flatten() {
+        for timing in entry.timings() {
+            if Some(timing.weekday) != weekday {
+                continue;
+            }
+
+            classes.push((
+                timing.start,
+                vec![
+                    Value::String(timing.start.format("%H:%M").to_string()),
+                    Value::String(timing.end.format("%H:%M").to_string()),
+                    Value::String(entry.course_code.clone()),
+                    Value::String(entry.course_title.clone()),
+                    Value::String(entry.course_type.clone()),
+                    Value::String(entry.venue.clone()),
+                    Value::String(entry.slot.clone()),
+                ],
+            ));
+        }
+    }
+    classes.sort_by_key(|(start, _)| *start);
+
+    Ok(Table {
+        columns: vec![
+            "start",
+            "end",
+            "course_code",
+            "course_title",
+            "course_type",
+            "venue",
+            "slot",
+        ],
+        rows: classes.into_iter().map(|(_, row)| row).collect(),
+    })
+}
+
+async fn timetable_calendar(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    semester_id: String,
+) -> Result<String> {
+    for (network_event, kind) in [
+        (
+            NetworkEvent::GetTimetable {
+                semester_id: Some(semester_id.clone()),
+            },
+            DataKind::Timetable,
+        ),
+        (
+            NetworkEvent::GetAcademicCalendar {
+                semester_id: Some(semester_id.clone()),
+            },
+            DataKind::AcademicCalendar,
+        ),
+    ] {
+        fetch(network_handler, app, network_event, kind, &semester_id).await?;
+    }
+
+    let app = app.lock().await;
+    let data = &app.state.data;
+
+    let events = export::timetable_events(
+        &data.timetable[&semester_id],
+        &data.academic_calendar[&semester_id],
+    )?;
+
+    Ok(export::write_calendar(
+        &format!("Time Table {}", data.semester_name(&semester_id)),
+        &events,
+    ))
+}
+
+fn write_output(mut table: Table, args: TableArgs) -> Result<()> {
+    if let Some(course) = args.course {
+        let column = table
+            .columns
+            .iter()
+            .position(|column| *column == "course_code")
+            .ok_or_else(|| eyre!("This table has no course column to filter by."))?;
+
+        table.rows.retain(|row| match row.get(column) {
+            Some(Value::String(course_code)) => course_code.eq_ignore_ascii_case(&course),
+            _ => false,
+        });
+    }
+
+    let contents = match (args.json, args.format) {
+        (true, _) => table.write(ExportFormat::Json),
+        (false, Some(format)) => table.write(format),
+        (false, None) => table.write_aligned(),
+    };
+
+    print_or_write(contents.into_bytes(), args.output)
+}
+
+/// Writes to the output file, or to stdout if there is none. Unlike `print!`, a closed
+/// stdout, like when piping into `head`, is an error instead of a panic.
+fn print_or_write(contents: Vec<u8>, output: Option<PathBuf>) -> Result<()> {
+    match output {
+        Some(path) => {
+            fs::write(&path, contents)?;
+            eprintln!("Wrote {}.", path.display());
+        }
+        None => {
+            let mut stdout = io::stdout();
+            stdout.write_all(&contents)?;
+            stdout.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Semesters and course codes in the cache, completed by the completion scripts.
+struct CompletionValues {
+    /// Ids and names of the semesters.
+    semesters: BTreeSet<String>,
+    course_codes: BTreeSet<String>,
+}
+
+impl CompletionValues {
+    fn from_data(data: &VtopData) -> CompletionValues {
+        let semesters = data
+            .semesters
+            .iter()
+            .flat_map(|semester| [semester.id.clone(), semester.name.clone()])
+            .collect();
+
+        let course_codes = data
+            .timetable
+            .values()
+            .flatten()
+            .map(|entry| entry.course_code.clone())
+            .chain(
+                data.attendance
+                    .values()
+                    .flatten()
+                    .map(|entry| entry.course_code.clone()),
+            )
+            .chain(
+                data.marks
+                    .values()
+                    .flatten()
+                    .map(|course| course.course_code.clone()),
+            )
+            .chain(
+                data.grades
+                    .values()
+                    .flatten()
+                    .map(|grade| grade.course_code.clone()),
+            )
+            .collect();
+
+        CompletionValues {
+            semesters,
+            course_codes,
+        }
+    }
+}
+
+/// The command line definition with the completion values as the possible values of
+/// `--semester` and `--course`. It is only used to generate completions, so values
+/// outside of them are still accepted.
+fn command_with_completion_values(values: CompletionValues) -> clap::Command {
+    let possible_values = |values: &BTreeSet<String>| {
+        PossibleValuesParser::new(values.iter().map(|value| PossibleValue::new(value.clone())))
+    };
+
+    let mut command = Cli::command();
+    let subcommands = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect::<Vec<String>>();
+
+    for subcommand in subcommands {
+        command = command.mut_subcommand(subcommand, |mut subcommand| {
+            let has_argument = |subcommand: &clap::Command, id: &str| {
+                subcommand.get_arguments().any(|arg| arg.get_id() == id)
+            };
+
+            if has_argument(&subcommand, "semester") && !values.semesters.is_empty() {
+                subcommand = subcommand.mut_arg("semester", |arg| {
+                    arg.value_parser(possible_values(&values.semesters))
+                });
+            }
+            if has_argument(&subcommand, "course") && !values.course_codes.is_empty() {
+                subcommand = subcommand.mut_arg("course", |arg| {
+                    arg.value_parser(possible_values(&values.course_codes))
+                });
+            }
+
+            subcommand
+        });
+    }
+
+    command
+}
@@ -0,0 +1,67 @@
+use std::{fs, path::PathBuf};
+
+use eyre::{eyre, Result};
+use serde::Deserialize;
+
+use crate::{
+    alerts::AlertRule,
+    export::{CalDavConfig, ExportFormat},
+    slots::CampusProfile,
+    util::{CONFIG_FILE_NAME, DEFAULT_CAMPUS, DOWNLOADS_DIRECTORY_NAME, PROJECT_DIRECTORY_NAME},
+};
+
+/// User configuration read from `config.toml` in the vtop-cli config directory.
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub download_directory: PathBuf,
+    /// Campus whose slot timings are used, either `vellore`, which is built in, or one
+    /// of the `campus_profiles`.
+    pub campus: String,
+    /// Slot timings of other campuses, added as `[[campus_profiles]]` tables with a
+    /// `name`, the `theory_hours` and `lab_hours` as `["HH:MM", "HH:MM"]` pairs, and the
+    /// `theory_grid` with a row of slots per weekday.
+    pub campus_profiles: Vec<CampusProfile>,
+    /// Format of the tables exported from the pages, `csv`, `json` or `markdown`.
+    pub export_format: ExportFormat,
+    /// Calendar collection the time table and exam schedule are synced to.
+    pub caldav: Option<CalDavConfig>,
+    /// Rules checked against the changes found after every fetch.
+    pub alerts: Vec<AlertRule>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            download_directory: dirs::download_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(DOWNLOADS_DIRECTORY_NAME),
+            campus: DEFAULT_CAMPUS.to_string(),
+            campus_profiles: vec![],
+            export_format: ExportFormat::default(),
+            caldav: None,
+            alerts: vec![],
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        Some(
+            dirs::config_dir()?
+                .join(PROJECT_DIRECTORY_NAME)
+                .join(CONFIG_FILE_NAME),
+        )
+    }
+
+    /// Reads the config file, falling back to the default config if it doesn't exist.
+    pub fn load() -> Result<Config> {
+        let path = match Config::path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Config::default()),
+        };
+
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents).map_err(|e| eyre!("Invalid config {}: {}", path.display(), e))
+    }
+}
@@ -0,0 +1,265 @@
+use std::slice;
+
+use eyre::{eyre, Result};
+use reqwest::{header::CONTENT_TYPE, Method, RequestBuilder, Url};
+use serde::Deserialize;
+
+use super::{write_calendar, CalendarEvent};
+use crate::util::PROJECT_DIRECTORY_NAME;
+
+/// Asks for the etag of every resource in the collection, which is enough to list them.
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop><d:getetag/></d:prop></d:propfind>"#;
+
+/// CalDAV collection to sync with, as written in the `caldav` table of the config file.
+#[derive(Clone, Deserialize)]
+pub struct CalDavConfig {
+    /// Url of the calendar collection, like `http://localhost:5232/user/vtop/`.
+    pub url: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Number of events written to and deleted from the collection by a sync.
+pub struct SyncSummary {
+    pub updated: usize,
+    pub deleted: usize,
+}
+
+/// Makes the collection hold exactly the given events of the semester, out of the ones
+/// created by vtop-cli. Every event is stored at a resource named after the semester and
+/// its UID, so syncing again overwrites the same resources, and the ones whose events
+/// are gone are deleted. Events of other semesters and events added to the collection
+/// by other clients are left alone.
+pub async fn sync_calendar(
+    config: &CalDavConfig,
+    semester_id: &str,
+    name: &str,
+    events: &[CalendarEvent],
+) -> Result<SyncSummary> {
+    let mut collection_url = config.url.trim().to_string();
+    if !collection_url.ends_with('/') {
+        collection_url.push('/');
+    }
+    let collection_url = Url::parse(&collection_url)
+        .map_err(|e| eyre!("Invalid CalDAV url {}: {}", config.url, e))?;
+
+    let http = reqwest::Client::builder()
+        .user_agent(PROJECT_DIRECTORY_NAME)
+        .build()?;
+    let request = |method: Method, url: Url| {
+        let request = http.request(method, url);
+        match &config.username {
+            Some(username) => request.basic_auth(username, config.password.as_ref()),
+            None => request,
+        }
+    };
+
+    let existing_urls = list_resources(request(
+        Method::from_bytes(b"PROPFIND").unwrap(),
+        collection_url.clone(),
+    ))
+    .await?
+    .into_iter()
+    .filter_map(|href| collection_url.join(&href).ok())
+    .filter(|url| is_owned_resource(url, semester_id))
+    .collect::<Vec<Url>>();
+
+    let mut event_urls = vec![];
+    for event in events {
+        let url = collection_url.join(&resource_name(semester_id, &event.uid))?;
+
+        request(Method::PUT, url.clone())
+            .header(CONTENT_TYPE, "text/calendar; charset=utf-8")
+            .body(write_calendar(name, slice::from_ref(event)))
+            .send()
+            .await?
+            .error_for_status()
+            .map_err(|e| eyre!("Failed to write {} to the calendar: {}", event.summary, e))?;
+
+        event_urls.push(url);
+    }
+
+    let mut deleted = 0;
+    for url in existing_urls {
+        if event_urls.contains(&url) {
+            continue;
+        }
+
+        request(Method::DELETE, url.clone())
+            .send()
+            .await?
+            .error_for_status()
+            .map_err(|e| eyre!("Failed to delete {} from the calendar: {}", url, e))?;
+
+        deleted += 1;
+    }
+
+    Ok(SyncSummary {
+        updated: event_urls.len(),
+        deleted,
+    })
+}
+
+/// Returns the hrefs of the collection and every resource in it.
+async fn list_resources(request: RequestBuilder) -> Result<Vec<String>> {
+    let body = request
+        .header("Depth", "1")
+        .header(CONTENT_TYPE, "application/xml; charset=utf-8")
+        .body(PROPFIND_BODY)
+        .send()
+        .await?
+        .error_for_status()
+        .map_err(|e| eyre!("Failed to list the CalDAV collection: {}", e))?
+        .text()
+        .await?;
+
+    Ok(response_hrefs(&body))
+}
+
+/// Picks the text of every `href` element out of a multistatus response, whatever
+/// prefix the server uses for the `DAV:` namespace.
+fn response_hrefs(body: &str) -> Vec<String> {
+    let mut hrefs = vec![];
+    let mut rest = body;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        let tag_end = match rest.find('>') {
+            Some(tag_end) => tag_end,
+            None => break,
+        };
+        let tag = &rest[..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let tag_name = tag.split_whitespace().next().unwrap_or_default();
+        let local_name = tag_name.rsplit(':').next().unwrap_or_default();
+        if tag_name.starts_with('/') || !local_name.eq_ignore_ascii_case("href") {
+            continue;
+        }
+
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        let href = rest[..text_end].trim();
+        if !href.is_empty() {
+            hrefs.push(href.to_string());
+        }
+    }
+
+    hrefs
+}
+
+/// Name of the resource holding the event with the given UID in the semester, like
+/// `vl20242505.cse1001-mon-0800-vtop-cli.ics`. The semester ends at the first `.`.
+fn resource_name(semester_id: &str, uid: &str) -> String {
+    format!(
+        "{}.{}.ics",
+        semester_prefix(semester_id),
+        uid.chars().map(resource_char).collect::<String>()
+    )
+}
+
+fn semester_prefix(semester_id: &str) -> String {
+    semester_id
+        .to_lowercase()
+        .chars()
+        .map(|ch| match resource_char(ch) {
+            '.' => '-',
+            ch => ch,
+        })
+        .collect()
+}
+
+/// Keeps the chars allowed in a resource name as they are, and replaces the others.
+fn resource_char(ch: char) -> char {
+    match ch {
+        'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => ch,
+        _ => '-',
+    }
+}
+
+/// Whether the resource holds an event of the semester created by vtop-cli.
+fn is_owned_resource(url: &Url, semester_id: &str) -> bool {
+    let resource = url.path().rsplit('/').next().unwrap_or_default();
+
+    resource.ends_with(&format!("-{}.ics", PROJECT_DIRECTORY_NAME))
+        && resource
+            .split_once('.')
+            .is_some_and(|(semester, _)| semester == semester_prefix(semester_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Listing of a collection holding an event of the semester, one of another semester
+    /// and one added by another client.
+    const MULTISTATUS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<multistatus xmlns="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <response>
+    <href>/user/vtop/</href>
+    <propstat><prop><getetag/></prop><status>HTTP/1.1 200 OK</status></propstat>
+  </response>
+  <D:response xmlns:D="DAV:">
+    <D:href>/user/vtop/vl20242505.cse1001-mon-0800-vtop-cli.ics</D:href>
+    <D:propstat><D:prop><D:getetag>"1"</D:getetag></D:prop></D:propstat>
+  </D:response>
+  <response>
+    <href>
+      /user/vtop/vl20242501.cse1001-mon-0800-vtop-cli.ics
+    </href>
+    <propstat><prop><getetag>"2"</getetag></prop></propstat>
+  </response>
+  <response>
+    <href>/user/vtop/birthday.ics</href>
+    <propstat><prop><getetag>"3"</getetag></prop></propstat>
+  </response>
+</multistatus>"#;
+
+    fn collection() -> Url {
+        Url::parse("http://localhost:5232/user/vtop/").unwrap()
+    }
+
+    #[test]
+    fn picks_hrefs_whatever_the_prefix() {
+        assert_eq!(
+            response_hrefs(MULTISTATUS),
+            vec![
+                "/user/vtop/",
+                "/user/vtop/vl20242505.cse1001-mon-0800-vtop-cli.ics",
+                "/user/vtop/vl20242501.cse1001-mon-0800-vtop-cli.ics",
+                "/user/vtop/birthday.ics",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_only_owned_resources_of_the_semester() {
+        let owned = response_hrefs(MULTISTATUS)
+            .iter()
+            .filter_map(|href| collection().join(href).ok())
+            .filter(|url| is_owned_resource(url, "VL20242505"))
+            .map(|url| url.path().to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            owned,
+            vec!["/user/vtop/vl20242505.cse1001-mon-0800-vtop-cli.ics"]
+        );
+    }
+
+    #[test]
+    fn names_resources_after_semester_and_uid() {
+        let name = resource_name("VL2024.25", "exam-cat-1-CSE1001-A1+TA1@vtop-cli");
+
+        assert_eq!(name, "vl2024-25.exam-cat-1-CSE1001-A1-TA1-vtop-cli.ics");
+        assert!(is_owned_resource(
+            &collection().join(&name).unwrap(),
+            "VL2024.25"
+        ));
+        assert!(!is_owned_resource(
+            &collection().join(&name).unwrap(),
+            "VL2024"
+        ));
+    }
+}
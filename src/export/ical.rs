@@ -0,0 +1,420 @@
+use chrono::{Datelike, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use eyre::{eyre, Result};
+
+use crate::{
+    models::{AcademicCalendar, ExamScheduleEntry, TimetableEntry},
+    slots::SlotTiming,
+    util::{PROJECT_DIRECTORY_NAME, VTOP_TIMEZONE, VTOP_UTC_OFFSET_SECS},
+};
+
+/// Lines longer than this many octets are folded, as required by RFC 5545.
+const MAXIMUM_LINE_OCTETS: usize = 75;
+
+/// Event of an exported calendar. Recurring events repeat weekly until `until`, with
+/// the extra dates in `extra_dates` and without the dates in `excluded_dates`.
+#[derive(Clone, Debug)]
+pub struct CalendarEvent {
+    /// Stays the same across exports, so calendars can update the event in place.
+    pub uid: String,
+    pub summary: String,
+    pub location: String,
+    pub description: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub until: Option<NaiveDateTime>,
+    pub excluded_dates: Vec<NaiveDateTime>,
+    pub extra_dates: Vec<NaiveDateTime>,
+}
+
+/// Returns one weekly event per class timing of the time table, from the first to the
+/// last instructional day of the academic calendar. Days without classes are excluded,
+/// and days following the day order of another weekday are added to that weekday's
+/// events.
+pub fn timetable_events(
+    timetable: &[TimetableEntry],
+    calendar: &AcademicCalendar,
+) -> Result<Vec<CalendarEvent>> {
+    let (first_day, last_day) = match (
+        calendar.first_instructional_day(),
+        calendar.last_instructional_day(),
+    ) {
+        (Some(first_day), Some(last_day)) => (first_day, last_day),
+        _ => {
+            return Err(eyre!(
+                "The academic calendar of the semester has no instructional days."
+            ))
+        }
+    };
+
+    let mut events = vec![];
+
+    for entry in timetable {
+        for timing in entry.timings() {
+            let first_date = first_day
+                .iter_days()
+                .find(|date| date.weekday() == timing.weekday)
+                .unwrap();
+            if first_date > last_day {
+                continue;
+            }
+
+            let mut excluded_dates = vec![];
+            let mut extra_dates = vec![];
+            for date in first_day.iter_days().take_while(|date| *date <= last_day) {
+                let follows_timing = calendar.day_order(date) == Some(timing.weekday);
+
+                if date.weekday() == timing.weekday && !follows_timing {
+                    excluded_dates.push(date.and_time(timing.start));
+                } else if date.weekday() != timing.weekday && follows_timing {
+                    extra_dates.push(date.and_time(timing.start));
+                }
+            }
+
+            events.push(CalendarEvent {
+                uid: class_uid(entry, &timing),
+                summary: format!("{} - {}", entry.course_code, entry.course_title),
+                location: entry.venue.clone(),
+                description: format!(
+                    "{}\nFaculty: {}\nSlot: {}\nClass: {}",
+                    entry.course_type, entry.faculty, entry.slot, entry.class_id
+                ),
+                start: first_date.and_time(timing.start),
+                end: first_date.and_time(timing.end),
+                until: Some(last_day.and_time(timing.end)),
+                excluded_dates,
+                extra_dates,
+            });
+        }
+    }
+
+    Ok(events)
+}
+
+fn class_uid(entry: &TimetableEntry, timing: &SlotTiming) -> String {
+    format!(
+        "{}-{}-{}@{}",
+        entry.class_id,
+        timing.weekday,
+        timing.start.format("%H%M"),
+        PROJECT_DIRECTORY_NAME
+    )
+    .to_lowercase()
+}
+
+/// Returns one event per scheduled exam. Exams without a date or a readable exam time
+/// are left out.
+pub fn exam_schedule_events(entries: &[ExamScheduleEntry]) -> Vec<CalendarEvent> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let date = entry.date?;
+            let (start, end) = parse_exam_time(&entry.exam_time)?;
+
+            Some(CalendarEvent {
+                uid: exam_uid(entry),
+                summary: format!(
+                    "{} - {} {}",
+                    entry.exam_type, entry.course_code, entry.course_title
+                ),
+                location: entry.venue.clone(),
+                description: format!(
+                    "Slot: {}\nSession: {}\nReporting time: {}\nSeat: {} {}",
+                    entry.slot,
+                    entry.session,
+                    entry.reporting_time,
+                    entry.seat_location,
+                    entry.seat_number
+                ),
+                start: date.and_time(start),
+                end: date.and_time(end),
+                until: None,
+                excluded_dates: vec![],
+                extra_dates: vec![],
+            })
+        })
+        .collect()
+}
+
+/// Parses an exam time like `09:30 AM - 12:30 PM` or `14:00 - 17:00`.
+fn parse_exam_time(exam_time: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let parse_time = |time: &str| {
+        let time = time.trim().to_uppercase();
+        NaiveTime::parse_from_str(&time, "%I:%M %p")
+            .or_else(|_| NaiveTime::parse_from_str(&time, "%I:%M%p"))
+            .or_else(|_| NaiveTime::parse_from_str(&time, "%H:%M"))
+            .ok()
+    };
+
+    let (start, end) = exam_time.split_once('-')?;
+    Some((parse_time(start)?, parse_time(end)?))
+}
+
+/// UID of an exam, kept apart from the exam of the other class of the course, like its
+/// lab, by the slot.
+fn exam_uid(entry: &ExamScheduleEntry) -> String {
+    format!(
+        "exam-{}-{}-{}@{}",
+        entry
+            .exam_type
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join("-"),
+        entry.course_code,
+        entry.slot.trim(),
+        PROJECT_DIRECTORY_NAME
+    )
+    .to_lowercase()
+}
+
+/// Writes the events as an RFC 5545 calendar, with times in VTOP's time zone.
+pub fn write_calendar(name: &str, events: &[CalendarEvent]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//{}//{}//EN", PROJECT_DIRECTORY_NAME, name),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+        "BEGIN:VTIMEZONE".to_string(),
+        format!("TZID:{}", VTOP_TIMEZONE),
+        "BEGIN:STANDARD".to_string(),
+        "DTSTART:19700101T000000".to_string(),
+        format!("TZOFFSETFROM:{}", utc_offset()),
+        format!("TZOFFSETTO:{}", utc_offset()),
+        "END:STANDARD".to_string(),
+        "END:VTIMEZONE".to_string(),
+    ];
+
+    for event in events {
+        lines.extend(event_lines(event));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+fn event_lines(event: &CalendarEvent) -> Vec<String> {
+    let local_time = |time: &NaiveDateTime| time.format("%Y%m%dT%H%M%S").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", event.uid),
+        format!("DTSTAMP:{}", Utc::now().format("%Y%m%dT%H%M%SZ")),
+        format!(
+            "DTSTART;TZID={}:{}",
+            VTOP_TIMEZONE,
+            local_time(&event.start)
+        ),
+        format!("DTEND;TZID={}:{}", VTOP_TIMEZONE, local_time(&event.end)),
+    ];
+
+    if let Some(until) = event.until {
+        // UNTIL has to be in UTC when the start has a time zone.
+        let until = FixedOffset::east_opt(VTOP_UTC_OFFSET_SECS)
+            .unwrap()
+            .from_local_datetime(&until)
+            .unwrap()
+            .with_timezone(&Utc);
+
+        lines.push(format!(
+            "RRULE:FREQ=WEEKLY;UNTIL={}",
+            until.format("%Y%m%dT%H%M%SZ")
+        ));
+    }
+
+    if !event.excluded_dates.is_empty() {
+        lines.push(format!(
+            "EXDATE;TZID={}:{}",
+            VTOP_TIMEZONE,
+            event
+                .excluded_dates
+                .iter()
+                .map(local_time)
+                .collect::<Vec<String>>()
+                .join(",")
+        ));
+    }
+
+    if !event.extra_dates.is_empty() {
+        lines.push(format!(
+            "RDATE;TZID={}:{}",
+            VTOP_TIMEZONE,
+            event
+                .extra_dates
+                .iter()
+                .map(local_time)
+                .collect::<Vec<String>>()
+                .join(",")
+        ));
+    }
+
+    lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+    if !event.location.is_empty() {
+        lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+    }
+    lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+    lines.push("END:VEVENT".to_string());
+
+    lines
+}
+
+fn utc_offset() -> String {
+    let minutes = VTOP_UTC_OFFSET_SECS / 60;
+    format!(
+        "{}{:02}{:02}",
+        if minutes < 0 { '-' } else { '+' },
+        minutes.abs() / 60,
+        minutes.abs() % 60
+    )
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Ends the line with CRLF, folding it into lines of at most 75 octets without
+/// splitting characters.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_octets = 0;
+
+    for ch in line.chars() {
+        if line_octets + ch.len_utf8() > MAXIMUM_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+
+        folded.push(ch);
+        line_octets += ch.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{NaiveDate, Weekday};
+
+    use super::*;
+    use crate::models::{CalendarDay, DayKind};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 7, day).unwrap()
+    }
+
+    /// Three weeks from Monday the 7th to Friday the 25th of July 2025, with a holiday
+    /// on Monday the 14th and Saturday the 19th following Monday's time table.
+    fn calendar() -> AcademicCalendar {
+        let days = [
+            (7, DayKind::Instructional),
+            (14, DayKind::Holiday),
+            (19, DayKind::DayOrder(Weekday::Mon)),
+            (25, DayKind::Instructional),
+        ]
+        .into_iter()
+        .map(|(day, kind)| {
+            (
+                date(day),
+                CalendarDay {
+                    date: date(day),
+                    kind,
+                    description: String::new(),
+                },
+            )
+        })
+        .collect::<BTreeMap<NaiveDate, CalendarDay>>();
+
+        AcademicCalendar {
+            months: vec![date(1)],
+            days,
+        }
+    }
+
+    fn lab() -> TimetableEntry {
+        TimetableEntry {
+            class_id: "VL2025260101001".to_string(),
+            course_code: "CSE1001".to_string(),
+            course_title: "Problem Solving, Lab".to_string(),
+            course_type: "Embedded Lab".to_string(),
+            slot: "L31+L32".to_string(),
+            venue: "SJT 316".to_string(),
+            faculty: "Faculty".to_string(),
+        }
+    }
+
+    fn unfold(folded: &str) -> String {
+        folded.trim_end_matches("\r\n").replace("\r\n ", "")
+    }
+
+    #[test]
+    fn folds_long_lines_at_75_octets() {
+        let line = "D".repeat(150);
+        let folded = fold_line(&line);
+
+        assert!(folded
+            .trim_end_matches("\r\n")
+            .split("\r\n")
+            .all(|line| line.len() <= MAXIMUM_LINE_OCTETS));
+        assert_eq!(folded.split("\r\n").next().unwrap().len(), 75);
+        assert_eq!(unfold(&folded), line);
+        assert_eq!(fold_line("SUMMARY:short"), "SUMMARY:short\r\n");
+    }
+
+    #[test]
+    fn folds_before_a_char_that_would_not_fit() {
+        let line = format!("{}é", "D".repeat(74));
+
+        assert_eq!(fold_line(&line), format!("{}\r\n é\r\n", "D".repeat(74)));
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(
+            escape_text("Lab; SJT 316, Floor 3\nBring \\ notes"),
+            r"Lab\; SJT 316\, Floor 3\nBring \\ notes"
+        );
+    }
+
+    #[test]
+    fn repeats_classes_until_the_last_day_in_utc() {
+        let events = timetable_events(&[lab()], &calendar()).unwrap();
+        let lines = event_lines(&events[0]);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].start, date(7).and_hms_opt(14, 0, 0).unwrap());
+        assert!(lines.contains(&"RRULE:FREQ=WEEKLY;UNTIL=20250725T101000Z".to_string()));
+    }
+
+    #[test]
+    fn skips_holidays_and_adds_day_orders() {
+        let events = timetable_events(&[lab()], &calendar()).unwrap();
+        let lines = event_lines(&events[0]);
+
+        assert_eq!(
+            events[0].excluded_dates,
+            vec![date(14).and_hms_opt(14, 0, 0).unwrap()]
+        );
+        assert_eq!(
+            events[0].extra_dates,
+            vec![date(19).and_hms_opt(14, 0, 0).unwrap()]
+        );
+        assert!(lines.contains(&"EXDATE;TZID=Asia/Kolkata:20250714T140000".to_string()));
+        assert!(lines.contains(&"RDATE;TZID=Asia/Kolkata:20250719T140000".to_string()));
+        assert!(lines.contains(&"SUMMARY:CSE1001 - Problem Solving\\, Lab".to_string()));
+    }
+
+    #[test]
+    fn needs_instructional_days() {
+        assert!(timetable_events(&[lab()], &AcademicCalendar::default()).is_err());
+    }
+}
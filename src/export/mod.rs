@@ -0,0 +1,7 @@
+pub use caldav::{sync_calendar, CalDavConfig, SyncSummary};
+pub use ical::{exam_schedule_events, timetable_events, write_calendar, CalendarEvent};
+pub use table::{DataTable, ExportFormat, Table};
+
+mod caldav;
+mod ical;
+mod table;
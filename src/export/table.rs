@@ -0,0 +1,446 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::models::{
+    AttendanceEntry, CourseGrade, CourseMarks, DataKind, ExamScheduleEntry, TimetableEntry,
+    VtopData,
+};
+
+/// File format of an exported table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    /// Pretty printed array of objects keyed by column name.
+    Json,
+    #[serde(alias = "md")]
+    #[value(alias = "md")]
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+/// Semester wise data which can be exported as a table. Grade history and faculty
+/// results aren't fetched from VTOP yet (their pages are still empty), so they have no
+/// tables until they are.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DataTable {
+    Timetable,
+    Attendance,
+    /// One row per mark component of every course.
+    Marks,
+    Grades,
+    ExamSchedule,
+}
+
+impl DataTable {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataTable::Timetable => "Time Table",
+            DataTable::Attendance => "Attendance",
+            DataTable::Marks => "Marks",
+            DataTable::Grades => "Grades",
+            DataTable::ExamSchedule => "Exam Schedule",
+        }
+    }
+
+    /// Builds the table out of the semester's data, if it is fetched.
+    pub fn table(&self, data: &VtopData, semester_id: &str) -> Option<Table> {
+        match self {
+            DataTable::Timetable => data.timetable.get(semester_id).map(|rows| table(rows)),
+            DataTable::Attendance => data.attendance.get(semester_id).map(|rows| table(rows)),
+            DataTable::Marks => data
+                .marks
+                .get(semester_id)
+                .map(|courses| marks_table(courses)),
+            DataTable::Grades => data.grades.get(semester_id).map(|rows| table(rows)),
+            DataTable::ExamSchedule => data.exam_schedule.get(semester_id).map(|rows| table(rows)),
+        }
+    }
+
+    /// Kind of the data the table is built out of.
+    pub fn kind(&self) -> DataKind {
+        match self {
+            DataTable::Timetable => DataKind::Timetable,
+            DataTable::Attendance => DataKind::Attendance,
+            DataTable::Marks => DataKind::Marks,
+            DataTable::Grades => DataKind::Grades,
+            DataTable::ExamSchedule => DataKind::ExamSchedule,
+        }
+    }
+}
+
+/// Rows of a table with their columns. Cells are JSON values so that numbers stay
+/// numbers in JSON, and missing values are `null`.
+pub struct Table {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    pub fn write(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Json => self.to_json(),
+            ExportFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    /// Writes the table as plain text with aligned columns, for reading in a terminal.
+    pub fn write_aligned(&self) -> String {
+        let mut lines = vec![self
+            .columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<String>>()];
+        lines.extend(
+            self.rows
+                .iter()
+                .map(|row| row.iter().map(cell_text).collect::<Vec<String>>()),
+        );
+
+        let mut widths = vec![0; self.columns.len()];
+        for line in &lines {
+            for (width, cell) in widths.iter_mut().zip(line) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        lines
+            .iter()
+            .map(|line| {
+                let text = line
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect::<Vec<String>>()
+                    .join("  ");
+
+                text.trim_end().to_string() + "\n"
+            })
+            .collect()
+    }
+
+    fn to_csv(&self) -> String {
+        let line = |cells: Vec<String>| {
+            cells
+                .iter()
+                .map(|cell| escape_csv(cell))
+                .collect::<Vec<String>>()
+                .join(",")
+                + "\r\n"
+        };
+
+        let mut csv = line(
+            self.columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect(),
+        );
+        for row in &self.rows {
+            csv.push_str(&line(row.iter().map(cell_text).collect()));
+        }
+
+        csv
+    }
+
+    fn to_json(&self) -> String {
+        let objects = self
+            .rows
+            .iter()
+            .map(|row| {
+                Value::Object(
+                    self.columns
+                        .iter()
+                        .map(|column| column.to_string())
+                        .zip(row.iter().cloned())
+                        .collect::<Map<String, Value>>(),
+                )
+            })
+            .collect::<Vec<Value>>();
+
+        let mut json = serde_json::to_string_pretty(&objects).unwrap_or_default();
+        json.push('\n');
+        json
+    }
+
+    fn to_markdown(&self) -> String {
+        let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+
+        let mut markdown = line(
+            self.columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect(),
+        );
+        markdown.push_str(&line(
+            self.columns.iter().map(|_| "---".to_string()).collect(),
+        ));
+        for row in &self.rows {
+            markdown.push_str(&line(
+                row.iter()
+                    .map(|cell| {
+                        cell_text(cell)
+                            .replace('|', "\\|")
+                            .replace(['\r', '\n'], " ")
+                    })
+                    .collect(),
+            ));
+        }
+
+        markdown
+    }
+}
+
+fn cell_text(cell: &Value) -> String {
+    match cell {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        cell => cell.to_string(),
+    }
+}
+
+fn escape_csv(cell: &str) -> String {
+    if cell.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// A model which is exported as one row of a table.
+trait TableRow {
+    const COLUMNS: &'static [&'static str];
+
+    fn cells(&self) -> Vec<Value>;
+}
+
+fn table<T: TableRow>(rows: &[T]) -> Table {
+    Table {
+        columns: T::COLUMNS.to_vec(),
+        rows: rows.iter().map(TableRow::cells).collect(),
+    }
+}
+
+fn text(text: &str) -> Value {
+    Value::String(text.to_string())
+}
+
+impl TableRow for TimetableEntry {
+    const COLUMNS: &'static [&'static str] = &[
+        "class_id",
+        "course_code",
+        "course_title",
+        "course_type",
+        "slot",
+        "venue",
+        "faculty",
+    ];
+
+    fn cells(&self) -> Vec<Value> {
+        vec![
+            text(&self.class_id),
+            text(&self.course_code),
+            text(&self.course_title),
+            text(&self.course_type),
+            text(&self.slot),
+            text(&self.venue),
+            text(&self.faculty),
+        ]
+    }
+}
+
+impl TableRow for AttendanceEntry {
+    const COLUMNS: &'static [&'static str] = &[
+        "class_id",
+        "course_code",
+        "course_title",
+        "course_type",
+        "slot",
+        "faculty",
+        "attended_classes",
+        "total_classes",
+        "percentage",
+    ];
+
+    fn cells(&self) -> Vec<Value> {
+        vec![
+            text(&self.class_id),
+            text(&self.course_code),
+            text(&self.course_title),
+            text(&self.course_type),
+            text(&self.slot),
+            text(&self.faculty),
+            self.attended_classes.into(),
+            self.total_classes.into(),
+            self.percentage.into(),
+        ]
+    }
+}
+
+impl TableRow for CourseGrade {
+    const COLUMNS: &'static [&'static str] = &[
+        "course_code",
+        "course_title",
+        "course_type",
+        "credits",
+        "grading_type",
+        "grand_total",
+        "grade",
+        "grade_points",
+    ];
+
+    fn cells(&self) -> Vec<Value> {
+        vec![
+            text(&self.course_code),
+            text(&self.course_title),
+            text(&self.course_type),
+            self.credits.into(),
+            text(&self.grading_type),
+            self.grand_total.into(),
+            text(&self.grade),
+            self.grade_points().into(),
+        ]
+    }
+}
+
+impl TableRow for ExamScheduleEntry {
+    const COLUMNS: &'static [&'static str] = &[
+        "exam_type",
+        "course_code",
+        "course_title",
+        "slot",
+        "date",
+        "session",
+        "reporting_time",
+        "exam_time",
+        "venue",
+        "seat_location",
+        "seat_number",
+    ];
+
+    fn cells(&self) -> Vec<Value> {
+        vec![
+            text(&self.exam_type),
+            text(&self.course_code),
+            text(&self.course_title),
+            text(&self.slot),
+            self.date
+                .map(|date| text(&date.format("%Y-%m-%d").to_string()))
+                .unwrap_or(Value::Null),
+            text(&self.session),
+            text(&self.reporting_time),
+            text(&self.exam_time),
+            text(&self.venue),
+            text(&self.seat_location),
+            text(&self.seat_number),
+        ]
+    }
+}
+
+fn marks_table(courses: &[CourseMarks]) -> Table {
+    Table {
+        columns: vec![
+            "class_id",
+            "course_code",
+            "course_title",
+            "course_type",
+            "faculty",
+            "slot",
+            "component",
+            "max_mark",
+            "weightage",
+            "status",
+            "scored_mark",
+            "weightage_mark",
+            "class_average",
+        ],
+        rows: courses
+            .iter()
+            .flat_map(|course| {
+                course.components.iter().map(move |component| {
+                    vec![
+                        text(&course.class_id),
+                        text(&course.course_code),
+                        text(&course.course_title),
+                        text(&course.course_type),
+                        text(&course.faculty),
+                        text(&course.slot),
+                        text(&component.title),
+                        component.max_mark.into(),
+                        component.weightage.into(),
+                        text(&component.status),
+                        component.scored_mark.into(),
+                        component.weightage_mark.into(),
+                        component.class_average.into(),
+                    ]
+                })
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mixed_table() -> Table {
+        Table {
+            columns: vec!["course_code", "venue", "faculty", "percentage"],
+            rows: vec![
+                vec![
+                    text("CSE1001"),
+                    text("SJT 101, Lab"),
+                    text("Dr. \"Ravi\" Kumar"),
+                    76.5.into(),
+                ],
+                vec![
+                    text("MAT1011"),
+                    text("TT|402"),
+                    text("Line one\nline two"),
+                    Value::Null,
+                ],
+            ],
+        }
+    }
+
+    #[test]
+    fn quotes_csv_cells_when_needed() {
+        assert_eq!(
+            mixed_table().write(ExportFormat::Csv),
+            "course_code,venue,faculty,percentage\r\n\
+             CSE1001,\"SJT 101, Lab\",\"Dr. \"\"Ravi\"\" Kumar\",76.5\r\n\
+             MAT1011,TT|402,\"Line one\nline two\",\r\n"
+        );
+    }
+
+    #[test]
+    fn escapes_pipes_and_newlines_in_markdown() {
+        assert_eq!(
+            mixed_table().write(ExportFormat::Markdown),
+            "| course_code | venue | faculty | percentage |\n\
+             | --- | --- | --- | --- |\n\
+             | CSE1001 | SJT 101, Lab | Dr. \"Ravi\" Kumar | 76.5 |\n\
+             | MAT1011 | TT\\|402 | Line one line two |  |\n"
+        );
+    }
+
+    #[test]
+    fn keeps_numbers_and_nulls_in_json() {
+        let json: Value = serde_json::from_str(&mixed_table().write(ExportFormat::Json)).unwrap();
+
+        assert_eq!(json[0]["percentage"], 76.5);
+        assert_eq!(json[1]["percentage"], Value::Null);
+        assert_eq!(json[1]["venue"], "TT|402");
+    }
+}
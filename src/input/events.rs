@@ -6,10 +6,19 @@ use std::{
     time::Duration,
 };
 
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
 use super::key::Key;
 
 pub enum KeyEvent {
     Input(Key),
+    /// Text pasted into the terminal at once.
+    Paste(String),
+    /// Left mouse button pressed at the cell.
+    Click {
+        column: u16,
+        row: u16,
+    },
     Tick,
 }
 
@@ -30,9 +39,20 @@ impl KeyEvents {
         tokio::spawn(async move {
             loop {
                 if crossterm::event::poll(tick_rate).expect("Failed to read terminal.") {
-                    if let crossterm::event::Event::Key(key) = crossterm::event::read().unwrap() {
-                        let key = Key::from(key);
-                        if let Err(_) = event_tx.send(KeyEvent::Input(key)).await {
+                    let event = match crossterm::event::read().unwrap() {
+                        crossterm::event::Event::Key(key) => Some(KeyEvent::Input(Key::from(key))),
+                        crossterm::event::Event::Paste(text) => Some(KeyEvent::Paste(text)),
+                        crossterm::event::Event::Mouse(MouseEvent {
+                            kind: MouseEventKind::Down(MouseButton::Left),
+                            column,
+                            row,
+                            ..
+                        }) => Some(KeyEvent::Click { column, row }),
+                        _ => None,
+                    };
+
+                    if let Some(event) = event {
+                        if event_tx.send(event).await.is_err() {
                             panic!("Failed to read terminal");
                         }
                     }
@@ -40,9 +60,9 @@ impl KeyEvents {
 
                 if event_stop_capture.load(Ordering::Relaxed) {
                     break;
-                } 
+                }
 
-                if let Err(_) = event_tx.send(KeyEvent::Tick).await {
+                if event_tx.send(KeyEvent::Tick).await.is_err() {
                     panic!("Failed to read terminal");
                 }
             }
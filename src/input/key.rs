@@ -65,12 +65,12 @@ pub enum Key {
     F12,
     /// Ctrl + Up
     CtrlUp,
-    /// Ctrl + Down 
+    /// Ctrl + Down
     CtrlDown,
     /// Ctrl + Left
     CtrlLeft,
     /// Ctrl + Right
-    CtrlRight, 
+    CtrlRight,
 
     Char(char),
     Ctrl(char),
@@ -231,7 +231,6 @@ impl From<event::KeyEvent> for Key {
                 ..
             } => Key::Alt(c),
 
-            
             event::KeyEvent {
                 code: event::KeyCode::Char(c),
                 modifiers: event::KeyModifiers::CONTROL,
@@ -1,7 +1,7 @@
 use app::App;
 use backtrace::Backtrace;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute, queue,
     style::Print,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -10,21 +10,30 @@ use eyre::Result;
 use input::KeyEvent;
 use std::{
     io::{self, Write},
-    panic::{self, PanicInfo},
+    panic::{self, PanicHookInfo},
     sync::Arc,
     time::Duration,
 };
 use tui::{backend::CrosstermBackend, Terminal};
 
+pub mod alerts;
 pub mod app;
+pub mod cli;
+pub mod config;
+pub mod export;
 pub mod input;
+pub mod models;
 pub mod network;
 pub mod pages;
+pub mod parser;
+pub mod slots;
 pub mod state;
+pub mod storage;
 pub mod ui;
 pub mod util;
+pub mod watch;
 
-pub fn panic_hook(info: &PanicInfo<'_>, in_alternate_screen: bool) {
+pub fn panic_hook(info: &PanicHookInfo<'_>, in_alternate_screen: bool) {
     let msg = match info.payload().downcast_ref::<&'static str>() {
         Some(s) => *s,
         None => match info.payload().downcast_ref::<String>() {
@@ -37,7 +46,13 @@ pub fn panic_hook(info: &PanicInfo<'_>, in_alternate_screen: bool) {
 
     if in_alternate_screen {
         disable_raw_mode().unwrap();
-        queue!(stdout, LeaveAlternateScreen, DisableMouseCapture).unwrap();
+        queue!(
+            stdout,
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableBracketedPaste
+        )
+        .unwrap();
     }
 
     if cfg!(debug_assertions) {
@@ -61,7 +76,12 @@ pub fn panic_hook(info: &PanicInfo<'_>, in_alternate_screen: bool) {
 
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     enable_raw_mode()?;
 
     panic::set_hook(Box::new(|info| {
@@ -83,9 +103,7 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
         let mut app = app.lock().await;
 
         if first_render {
-            // DO SOMETHING AT FIRST RENDER
-            // Like dispatching a notification
-            // app.state.dispatch_notification("Long notification string hello hello hello".to_string());
+            app.on_first_render().await;
             first_render = false;
         }
 
@@ -93,10 +111,12 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
 
         match events.next().await {
             KeyEvent::Input(key) => app.do_action(key).await,
+            KeyEvent::Paste(text) => app.paste(&text),
+            KeyEvent::Click { column, row } => app.click(column, row).await,
             KeyEvent::Tick => app.update_on_tick().await,
         }
 
-        if app.exit_app == true {
+        if app.exit_app {
             events.close();
             break;
         }
@@ -109,7 +129,12 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     disable_raw_mode()?;
 
     let mut stdout = io::stdout();
-    execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(
+        stdout,
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
 
     Ok(())
 }
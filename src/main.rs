@@ -1,7 +1,10 @@
 use std::panic;
+use std::process;
 use std::sync::Arc;
 
+use clap::Parser;
 use vtop_cli::app::App;
+use vtop_cli::cli::{self, Cli};
 use vtop_cli::network::NetworkEvent;
 use vtop_cli::network::NetworkHandler;
 use vtop_cli::panic_hook;
@@ -13,11 +16,32 @@ async fn main() {
         panic_hook(info, false);
     }));
 
+    let cli = Cli::parse();
+    let cookie = cli.cookie().unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+
+    if let Some(command) = cli.command {
+        if let Err(e) = cli::run(command, cookie).await {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
     let (network_event_tx, mut network_event_rx) = tokio::sync::mpsc::channel::<NetworkEvent>(100);
 
     let app = Arc::new(tokio::sync::Mutex::new(App::new(network_event_tx.clone())));
     let app_ui = Arc::clone(&app);
 
+    if let Some(cookie) = cookie {
+        app.lock()
+            .await
+            .dispatch(NetworkEvent::LogIn { cookie })
+            .await;
+    }
+
     tokio::spawn(async move {
         let mut network_handler = NetworkHandler::new(&app);
         while let Some(network_event) = network_event_rx.recv().await {
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::slots::SlotTiming;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DayKind {
+    Instructional,
+    /// Instructional day following the time table of another weekday.
+    DayOrder(Weekday),
+    Holiday,
+    NoInstruction,
+    Exam,
+    /// Events which don't change the classes of the day.
+    Other,
+}
+
+impl DayKind {
+    pub fn has_classes(&self) -> bool {
+        matches!(
+            self,
+            DayKind::Instructional | DayKind::DayOrder(_) | DayKind::Other
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CalendarDay {
+    pub date: NaiveDate,
+    pub kind: DayKind,
+    pub description: String,
+}
+
+/// Academic calendar of a semester, filled a month at a time.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct AcademicCalendar {
+    /// First day of every month of the semester.
+    pub months: Vec<NaiveDate>,
+    pub days: BTreeMap<NaiveDate, CalendarDay>,
+}
+
+impl AcademicCalendar {
+    /// Weekday whose time table is followed on the date, or `None` if there are no
+    /// classes. Dates missing from the calendar follow their own weekday.
+    pub fn day_order(&self, date: NaiveDate) -> Option<Weekday> {
+        match self.days.get(&date).map(|day| day.kind) {
+            Some(DayKind::DayOrder(weekday)) => Some(weekday),
+            Some(kind) if !kind.has_classes() => None,
+            _ => Some(date.weekday()),
+        }
+    }
+
+    pub fn first_instructional_day(&self) -> Option<NaiveDate> {
+        self.instructional_days().min()
+    }
+
+    pub fn last_instructional_day(&self) -> Option<NaiveDate> {
+        self.instructional_days().max()
+    }
+
+    fn instructional_days(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.days
+            .values()
+            .filter(|day| matches!(day.kind, DayKind::Instructional | DayKind::DayOrder(_)))
+            .map(|day| day.date)
+    }
+
+    /// Dates without classes, like holidays and exam days.
+    pub fn days_without_classes(&self) -> Vec<NaiveDate> {
+        self.days
+            .values()
+            .filter(|day| !day.kind.has_classes())
+            .map(|day| day.date)
+            .collect()
+    }
+
+    /// Number of classes held during the timings from `from` to `to`, both included,
+    /// skipping the days without classes and following the day orders.
+    pub fn class_count(&self, timings: &[SlotTiming], from: NaiveDate, to: NaiveDate) -> u32 {
+        let mut count = 0;
+        let mut date = from;
+
+        while date <= to {
+            if let Some(weekday) = self.day_order(date) {
+                count += timings
+                    .iter()
+                    .filter(|timing| timing.weekday == weekday)
+                    .count() as u32;
+            }
+            date += Duration::days(1);
+        }
+
+        count
+    }
+}
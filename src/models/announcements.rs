@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClassMessage {
+    pub course: String,
+    pub faculty: String,
+    pub posted_on: String,
+    pub message: String,
+}
+
+impl ClassMessage {
+    /// Class messages have no id, so a message is identified by all of its fields.
+    pub fn key(&self) -> String {
+        format!(
+            "{}|{}|{}|{}",
+            self.course, self.faculty, self.posted_on, self.message
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpotlightItem {
+    pub category: String,
+    pub title: String,
+    pub posted_on: String,
+    pub link: Option<String>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    slots::{slot_timings, SlotTiming},
+    util::ATTENDANCE_THRESHOLD,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttendanceEntry {
+    pub class_id: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub course_type: String,
+    pub slot: String,
+    pub faculty: String,
+    pub attended_classes: u32,
+    pub total_classes: u32,
+    pub percentage: f64,
+}
+
+impl AttendanceEntry {
+    pub fn is_at_risk(&self) -> bool {
+        self.percentage < ATTENDANCE_THRESHOLD
+    }
+
+    pub fn timings(&self) -> Vec<SlotTiming> {
+        slot_timings(&self.slot)
+    }
+
+    /// Number of the remaining classes which can be missed while staying at the
+    /// threshold. It is negative if attending every remaining class isn't enough.
+    pub fn classes_to_spare(&self, remaining_classes: u32) -> i64 {
+        let attended = (self.attended_classes + remaining_classes) as f64;
+        let total = (self.total_classes + remaining_classes) as f64;
+
+        (attended - total * ATTENDANCE_THRESHOLD / 100.0).floor() as i64
+    }
+
+    /// Attendance percentage after attending every remaining class.
+    pub fn best_percentage(&self, remaining_classes: u32) -> f64 {
+        let total = self.total_classes + remaining_classes;
+        if total == 0 {
+            return 0.0;
+        }
+
+        (self.attended_classes + remaining_classes) as f64 * 100.0 / total as f64
+    }
+}
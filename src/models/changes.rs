@@ -0,0 +1,447 @@
+use std::fmt::{self, Display};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use super::{AttendanceEntry, ClassMessage, CourseGrade, CourseMarks, SpotlightItem};
+
+/// Something that changed on VTOP between two fetches.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Change {
+    pub detected_at: DateTime<Local>,
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChangeKind {
+    MarksPosted {
+        course_code: String,
+        component: String,
+        scored_mark: f64,
+        max_mark: f64,
+    },
+    /// A mark which was already posted was changed, like after a revaluation.
+    MarksChanged {
+        course_code: String,
+        component: String,
+        previous_mark: f64,
+        scored_mark: f64,
+        max_mark: f64,
+    },
+    GradePosted {
+        course_code: String,
+        grade: String,
+    },
+    AttendanceChanged {
+        course_code: String,
+        previous_percentage: f64,
+        percentage: f64,
+    },
+    NewMessage {
+        course: String,
+        faculty: String,
+        message: String,
+    },
+    NewSpotlight {
+        category: String,
+        title: String,
+    },
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::MarksPosted {
+                course_code,
+                component,
+                scored_mark,
+                max_mark,
+            } => write!(
+                f,
+                "{} marks posted for {}: {}/{}",
+                component, course_code, scored_mark, max_mark
+            ),
+            ChangeKind::MarksChanged {
+                course_code,
+                component,
+                previous_mark,
+                scored_mark,
+                max_mark,
+            } => write!(
+                f,
+                "{} marks for {} changed from {} to {}/{}",
+                component, course_code, previous_mark, scored_mark, max_mark
+            ),
+            ChangeKind::GradePosted { course_code, grade } => {
+                write!(f, "Grade {} posted for {}", grade, course_code)
+            }
+            ChangeKind::AttendanceChanged {
+                course_code,
+                previous_percentage,
+                percentage,
+            } => write!(
+                f,
+                "Attendance for {} {} to {:.0}%",
+                course_code,
+                if percentage < previous_percentage {
+                    "dropped"
+                } else {
+                    "rose"
+                },
+                percentage
+            ),
+            ChangeKind::NewMessage {
+                course, message, ..
+            } => write!(f, "New message for {}: {}", course, message),
+            ChangeKind::NewSpotlight { category, title } => {
+                write!(f, "New in the spotlight [{}]: {}", category, title)
+            }
+        }
+    }
+}
+
+impl Change {
+    fn now(kind: ChangeKind) -> Change {
+        Change {
+            detected_at: Local::now(),
+            kind,
+        }
+    }
+}
+
+/// Marks which were posted or changed in `new`. Components are matched by class id and
+/// title.
+pub fn marks_changes(old: &[CourseMarks], new: &[CourseMarks]) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for course in new {
+        let old_course = old.iter().find(|old| old.class_id == course.class_id);
+
+        for component in course.components.iter() {
+            let scored_mark = match component.scored_mark {
+                Some(scored_mark) => scored_mark,
+                None => continue,
+            };
+            let previous_mark = old_course
+                .and_then(|old| {
+                    old.components
+                        .iter()
+                        .find(|old| old.title == component.title)
+                })
+                .and_then(|old| old.scored_mark);
+
+            let kind = match previous_mark {
+                None => ChangeKind::MarksPosted {
+                    course_code: course.course_code.clone(),
+                    component: component.title.clone(),
+                    scored_mark,
+                    max_mark: component.max_mark,
+                },
+                Some(previous_mark) if previous_mark != scored_mark => ChangeKind::MarksChanged {
+                    course_code: course.course_code.clone(),
+                    component: component.title.clone(),
+                    previous_mark,
+                    scored_mark,
+                    max_mark: component.max_mark,
+                },
+                Some(_) => continue,
+            };
+
+            changes.push(Change::now(kind));
+        }
+    }
+
+    changes
+}
+
+/// Grades which were posted or changed in `new`.
+pub fn grades_changes(old: &[CourseGrade], new: &[CourseGrade]) -> Vec<Change> {
+    new.iter()
+        .filter(|grade| !grade.grade.is_empty())
+        .filter(|grade| {
+            !old.iter()
+                .any(|old| old.course_code == grade.course_code && old.grade == grade.grade)
+        })
+        .map(|grade| {
+            Change::now(ChangeKind::GradePosted {
+                course_code: grade.course_code.clone(),
+                grade: grade.grade.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Courses whose attendance percentage changed. Courses are matched by class id.
+pub fn attendance_changes(old: &[AttendanceEntry], new: &[AttendanceEntry]) -> Vec<Change> {
+    new.iter()
+        .filter_map(|entry| {
+            let old = old.iter().find(|old| old.class_id == entry.class_id)?;
+            if old.percentage == entry.percentage {
+                return None;
+            }
+
+            Some(Change::now(ChangeKind::AttendanceChanged {
+                course_code: entry.course_code.clone(),
+                previous_percentage: old.percentage,
+                percentage: entry.percentage,
+            }))
+        })
+        .collect()
+}
+
+pub fn class_message_changes(old: &[ClassMessage], new: &[ClassMessage]) -> Vec<Change> {
+    new.iter()
+        .filter(|message| !old.iter().any(|old| old.key() == message.key()))
+        .map(|message| {
+            Change::now(ChangeKind::NewMessage {
+                course: message.course.clone(),
+                faculty: message.faculty.clone(),
+                message: message.message.clone(),
+            })
+        })
+        .collect()
+}
+
+pub fn spotlight_changes(old: &[SpotlightItem], new: &[SpotlightItem]) -> Vec<Change> {
+    new.iter()
+        .filter(|item| {
+            !old.iter()
+                .any(|old| old.category == item.category && old.title == item.title)
+        })
+        .map(|item| {
+            Change::now(ChangeKind::NewSpotlight {
+                category: item.category.clone(),
+                title: item.title.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MarkComponent;
+
+    fn kinds(changes: Vec<Change>) -> Vec<ChangeKind> {
+        changes.into_iter().map(|change| change.kind).collect()
+    }
+
+    fn component(title: &str, scored_mark: Option<f64>) -> MarkComponent {
+        MarkComponent {
+            title: title.to_string(),
+            max_mark: 50.0,
+            weightage: 15.0,
+            status: "Present".to_string(),
+            scored_mark,
+            weightage_mark: None,
+            class_average: None,
+        }
+    }
+
+    fn course_marks(class_id: &str, components: Vec<MarkComponent>) -> CourseMarks {
+        CourseMarks {
+            class_id: class_id.to_string(),
+            course_code: format!("CSE{}", &class_id[class_id.len() - 4..]),
+            course_title: "Course".to_string(),
+            course_type: "Embedded Theory".to_string(),
+            faculty: "Faculty".to_string(),
+            slot: "A1+TA1".to_string(),
+            components,
+        }
+    }
+
+    fn grade(course_code: &str, grade: &str) -> CourseGrade {
+        CourseGrade {
+            course_code: course_code.to_string(),
+            course_title: "Course".to_string(),
+            course_type: "Theory Only".to_string(),
+            credits: 3.0,
+            grading_type: "RG".to_string(),
+            grand_total: Some(81.0),
+            grade: grade.to_string(),
+        }
+    }
+
+    fn attendance(class_id: &str, percentage: f64) -> AttendanceEntry {
+        AttendanceEntry {
+            class_id: class_id.to_string(),
+            course_code: format!("CSE{}", &class_id[class_id.len() - 4..]),
+            course_title: "Course".to_string(),
+            course_type: "Embedded Theory".to_string(),
+            slot: "A1+TA1".to_string(),
+            faculty: "Faculty".to_string(),
+            attended_classes: 0,
+            total_classes: 0,
+            percentage,
+        }
+    }
+
+    fn message(course: &str, text: &str) -> ClassMessage {
+        ClassMessage {
+            course: course.to_string(),
+            faculty: "Faculty".to_string(),
+            posted_on: "12-Nov-2025".to_string(),
+            message: text.to_string(),
+        }
+    }
+
+    fn spotlight(category: &str, title: &str) -> SpotlightItem {
+        SpotlightItem {
+            category: category.to_string(),
+            title: title.to_string(),
+            posted_on: "12-Nov-2025".to_string(),
+            link: None,
+        }
+    }
+
+    #[test]
+    fn posts_every_scored_mark_of_the_first_fetch() {
+        let new = vec![course_marks(
+            "VL2025260101001",
+            vec![component("CAT1", Some(38.0)), component("CAT2", None)],
+        )];
+
+        assert_eq!(
+            kinds(marks_changes(&[], &new)),
+            vec![ChangeKind::MarksPosted {
+                course_code: "CSE1001".to_string(),
+                component: "CAT1".to_string(),
+                scored_mark: 38.0,
+                max_mark: 50.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn tells_posted_marks_from_changed_ones() {
+        let old = vec![course_marks(
+            "VL2025260101001",
+            vec![component("CAT1", Some(38.0)), component("CAT2", None)],
+        )];
+        let new = vec![course_marks(
+            "VL2025260101001",
+            vec![component("CAT1", Some(40.0)), component("CAT2", Some(25.0))],
+        )];
+
+        assert_eq!(
+            kinds(marks_changes(&old, &new)),
+            vec![
+                ChangeKind::MarksChanged {
+                    course_code: "CSE1001".to_string(),
+                    component: "CAT1".to_string(),
+                    previous_mark: 38.0,
+                    scored_mark: 40.0,
+                    max_mark: 50.0,
+                },
+                ChangeKind::MarksPosted {
+                    course_code: "CSE1001".to_string(),
+                    component: "CAT2".to_string(),
+                    scored_mark: 25.0,
+                    max_mark: 50.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_courses_gone_from_marks() {
+        let old = vec![
+            course_marks("VL2025260101001", vec![component("CAT1", Some(38.0))]),
+            course_marks("VL2025260101002", vec![component("CAT1", Some(30.0))]),
+        ];
+        let new = vec![course_marks(
+            "VL2025260101001",
+            vec![component("CAT1", Some(38.0))],
+        )];
+
+        assert!(marks_changes(&old, &new).is_empty());
+        assert!(marks_changes(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn posts_new_and_changed_grades() {
+        let old = vec![grade("CSE1001", "A"), grade("CSE1002", "")];
+        let new = vec![grade("CSE1001", "S"), grade("CSE1002", "B")];
+
+        assert_eq!(
+            kinds(grades_changes(
+                &[],
+                &[grade("CSE1001", "A"), grade("CSE1002", "")]
+            )),
+            vec![ChangeKind::GradePosted {
+                course_code: "CSE1001".to_string(),
+                grade: "A".to_string(),
+            }]
+        );
+        assert_eq!(kinds(grades_changes(&old, &new)).len(), 2);
+        assert!(grades_changes(&old, &old).is_empty());
+        assert!(grades_changes(&old, &old[..1]).is_empty());
+    }
+
+    #[test]
+    fn compares_attendance_of_the_same_class() {
+        let old = vec![
+            attendance("VL2025260101001", 80.0),
+            attendance("VL2025260101002", 90.0),
+        ];
+        let new = vec![attendance("VL2025260101001", 75.0)];
+
+        assert!(attendance_changes(&[], &new).is_empty());
+        assert!(attendance_changes(&old, &old).is_empty());
+        assert_eq!(
+            kinds(attendance_changes(&old, &new)),
+            vec![ChangeKind::AttendanceChanged {
+                course_code: "CSE1001".to_string(),
+                previous_percentage: 80.0,
+                percentage: 75.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_only_unseen_messages() {
+        let old = vec![
+            message("CSE1001", "Class moved"),
+            message("CSE1002", "Quiz"),
+        ];
+        let new = vec![
+            message("CSE1001", "Class moved"),
+            message("CSE1001", "No class"),
+        ];
+
+        assert_eq!(kinds(class_message_changes(&[], &new)).len(), 2);
+        assert!(class_message_changes(&old, &old).is_empty());
+        assert_eq!(
+            kinds(class_message_changes(&old, &new)),
+            vec![ChangeKind::NewMessage {
+                course: "CSE1001".to_string(),
+                faculty: "Faculty".to_string(),
+                message: "No class".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_only_unseen_spotlight_items() {
+        let old = vec![
+            spotlight("Academics", "FAT schedule"),
+            spotlight("Events", "Riviera"),
+        ];
+        let new = vec![
+            spotlight("Academics", "FAT schedule"),
+            spotlight("Academics", "Re-FAT"),
+        ];
+
+        assert_eq!(kinds(spotlight_changes(&[], &new)).len(), 2);
+        assert!(spotlight_changes(&old, &old).is_empty());
+        assert_eq!(
+            kinds(spotlight_changes(&old, &new)),
+            vec![ChangeKind::NewSpotlight {
+                category: "Academics".to_string(),
+                title: "Re-FAT".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::sanitize_file_name;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Course {
+    pub class_id: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub course_type: String,
+    pub faculty: String,
+    pub slot: String,
+}
+
+impl Course {
+    pub fn display_name(&self) -> String {
+        format!(
+            "{} - {} ({}) [{}]",
+            self.course_code, self.course_title, self.course_type, self.slot
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MaterialLink {
+    pub name: String,
+    pub url: String,
+}
+
+/// A course page entry: every reference material posted for a topic on a date.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CourseMaterial {
+    pub date: String,
+    pub topic: String,
+    pub links: Vec<MaterialLink>,
+}
+
+impl CourseMaterial {
+    /// Directory the entry is downloaded into, `<course code>/<date> - <topic>`,
+    /// relative to the downloads directory.
+    pub fn relative_directory(&self, course_code: &str) -> PathBuf {
+        let mut path = PathBuf::from(sanitize_file_name(course_code));
+        path.push(sanitize_file_name(&format!(
+            "{} - {}",
+            self.date, self.topic
+        )));
+        path
+    }
+}
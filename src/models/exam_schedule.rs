@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExamScheduleEntry {
+    /// Exam like `CAT1`, `CAT2` or `FAT`.
+    pub exam_type: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub slot: String,
+    pub date: Option<NaiveDate>,
+    /// Session like `FN` or `AN`.
+    pub session: String,
+    pub reporting_time: String,
+    pub exam_time: String,
+    pub venue: String,
+    pub seat_location: String,
+    pub seat_number: String,
+}
+
+impl ExamScheduleEntry {
+    /// Describes how far the exam is, like `FAT for CSE3001 in 4 days`.
+    pub fn countdown(&self, today: NaiveDate) -> String {
+        let days = match self.date {
+            Some(date) => (date - today).num_days(),
+            None => {
+                return format!(
+                    "{} for {}, date not announced",
+                    self.exam_type, self.course_code
+                )
+            }
+        };
+
+        let when = match days {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            -1 => "was yesterday".to_string(),
+            days if days < 0 => format!("was {} days ago", -days),
+            days => format!("in {} days", days),
+        };
+
+        format!("{} for {} {}", self.exam_type, self.course_code, when)
+    }
+}
+
+/// Groups the upcoming exams by date, keeping only the dates with more than one exam.
+pub fn same_day_exams(
+    entries: &[ExamScheduleEntry],
+    today: NaiveDate,
+) -> BTreeMap<NaiveDate, Vec<&ExamScheduleEntry>> {
+    let mut exams_by_date: BTreeMap<NaiveDate, Vec<&ExamScheduleEntry>> = BTreeMap::new();
+
+    for entry in entries {
+        if let Some(date) = entry.date.filter(|date| *date >= today) {
+            exams_by_date.entry(date).or_default().push(entry);
+        }
+    }
+
+    exams_by_date.retain(|_, exams| exams.len() > 1);
+    exams_by_date
+}
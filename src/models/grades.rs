@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CourseGrade {
+    pub course_code: String,
+    pub course_title: String,
+    pub course_type: String,
+    pub credits: f64,
+    pub grading_type: String,
+    pub grand_total: Option<f64>,
+    pub grade: String,
+}
+
+impl CourseGrade {
+    /// Grade points of the grade on VIT's ten point scale.
+    pub fn grade_points(&self) -> Option<f64> {
+        match self.grade.as_str() {
+            "S" => Some(10.0),
+            "A" => Some(9.0),
+            "B" => Some(8.0),
+            "C" => Some(7.0),
+            "D" => Some(6.0),
+            "E" => Some(5.0),
+            "F" | "N" => Some(0.0),
+            _ => None,
+        }
+    }
+}
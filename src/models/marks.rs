@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MarkComponent {
+    pub title: String,
+    pub max_mark: f64,
+    pub weightage: f64,
+    pub status: String,
+    pub scored_mark: Option<f64>,
+    pub weightage_mark: Option<f64>,
+    pub class_average: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CourseMarks {
+    pub class_id: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub course_type: String,
+    pub faculty: String,
+    pub slot: String,
+    pub components: Vec<MarkComponent>,
+}
+
+impl CourseMarks {
+    /// The last component of the course with a scored mark.
+    pub fn latest_component(&self) -> Option<&MarkComponent> {
+        self.components
+            .iter()
+            .rev()
+            .find(|component| component.scored_mark.is_some())
+    }
+}
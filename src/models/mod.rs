@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::network::Download;
+
+pub use academic_calendar::{AcademicCalendar, CalendarDay, DayKind};
+pub use announcements::{ClassMessage, SpotlightItem};
+pub use attendance::AttendanceEntry;
+pub use changes::{
+    attendance_changes, class_message_changes, grades_changes, marks_changes, spotlight_changes,
+    Change, ChangeKind,
+};
+pub use course_page::{Course, CourseMaterial, MaterialLink};
+pub use exam_schedule::{same_day_exams, ExamScheduleEntry};
+pub use grades::CourseGrade;
+pub use marks::{CourseMarks, MarkComponent};
+pub use room::{Room, RoomClass};
+pub use timetable::TimetableEntry;
+
+mod academic_calendar;
+mod announcements;
+mod attendance;
+mod changes;
+mod course_page;
+mod exam_schedule;
+mod grades;
+mod marks;
+mod room;
+mod timetable;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Semester {
+    pub id: String,
+    pub name: String,
+}
+
+/// Course page data fetched from VTOP. Courses are keyed by semester id and
+/// course materials are keyed by class id.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct CoursePageData {
+    pub courses: HashMap<String, Vec<Course>>,
+    pub materials: HashMap<String, Vec<CourseMaterial>>,
+}
+
+/// Kind of data fetched from VTOP, to keep track of when it was fetched.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DataKind {
+    Semesters,
+    Timetable,
+    Attendance,
+    Marks,
+    Grades,
+    ExamSchedule,
+    AcademicCalendar,
+    ClassMessages,
+    Spotlight,
+    CoursePageCourses,
+    CourseMaterials,
+    Room,
+}
+
+/// All the data fetched from VTOP, shared by every tab. Semester wise data is keyed by
+/// semester id. Everything but the downloads is cached between runs.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VtopData {
+    /// Semesters listed on the time table page, the current semester first.
+    pub semesters: Vec<Semester>,
+    /// Semester shown by the pages which don't pin their own, the current semester
+    /// if `None`.
+    pub global_semester_id: Option<String>,
+    pub timetable: HashMap<String, Vec<TimetableEntry>>,
+    pub attendance: HashMap<String, Vec<AttendanceEntry>>,
+    pub marks: HashMap<String, Vec<CourseMarks>>,
+    pub grades: HashMap<String, Vec<CourseGrade>>,
+    pub exam_schedule: HashMap<String, Vec<ExamScheduleEntry>>,
+    /// Keys of the days with more than one exam which were already notified about.
+    pub notified_same_day_exams: HashSet<String>,
+    pub academic_calendar: HashMap<String, AcademicCalendar>,
+    pub class_messages: Vec<ClassMessage>,
+    /// Keys of the class messages already viewed in the app.
+    pub read_messages: HashSet<String>,
+    pub spotlight: Vec<SpotlightItem>,
+    pub course_page: CoursePageData,
+    /// Room schedules keyed by room code.
+    pub rooms: BTreeMap<String, Room>,
+    #[serde(skip)]
+    pub downloads: Vec<Download>,
+    /// Changes detected between fetches, the latest last.
+    pub changes: Vec<Change>,
+    /// When every piece of data was last fetched, keyed by its kind and key.
+    fetched_at: HashMap<String, DateTime<Local>>,
+}
+
+impl VtopData {
+    pub fn current_semester(&self) -> Option<&Semester> {
+        self.semesters.first()
+    }
+
+    pub fn global_semester(&self) -> Option<&Semester> {
+        self.global_semester_id
+            .as_ref()
+            .and_then(|id| self.semesters.iter().find(|semester| &semester.id == id))
+            .or_else(|| self.current_semester())
+    }
+
+    /// Records that the data of the kind with the given key, like a semester id, was just
+    /// fetched. Data without a key, like the semesters, uses an empty key.
+    pub fn mark_fetched(&mut self, kind: DataKind, key: &str) {
+        self.fetched_at.insert(fetch_key(kind, key), Local::now());
+    }
+
+    pub fn fetched_at(&self, kind: DataKind, key: &str) -> Option<DateTime<Local>> {
+        self.fetched_at.get(&fetch_key(kind, key)).copied()
+    }
+
+    /// Name of the semester with the given id, or the id itself if it isn't listed.
+    pub fn semester_name(&self, semester_id: &str) -> String {
+        self.semesters
+            .iter()
+            .find(|semester| semester.id == semester_id)
+            .map(|semester| semester.name.clone())
+            .unwrap_or_else(|| semester_id.to_string())
+    }
+
+    pub fn unread_messages(&self) -> Vec<&ClassMessage> {
+        self.class_messages
+            .iter()
+            .filter(|message| !self.read_messages.contains(&message.key()))
+            .collect()
+    }
+}
+
+fn fetch_key(kind: DataKind, key: &str) -> String {
+    format!("{:?}/{}", kind, key)
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use crate::slots::{slot_timings, SlotTiming};
+
+const BUILDINGS: [(&str, &str); 10] = [
+    ("SJT", "Silver Jubilee Tower"),
+    ("TT", "Technology Tower"),
+    ("SMV", "Sir M. Visvesvaraya Building"),
+    ("GDN", "G. D. Naidu Block"),
+    ("MB", "Main Building"),
+    ("MGB", "Mahatma Gandhi Block"),
+    ("CDMM", "Dr. M. Chenna Reddy Block"),
+    ("PRP", "Pearl Research Park"),
+    ("AB1", "Academic Block 1"),
+    ("AB2", "Academic Block 2"),
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoomClass {
+    pub slot: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub faculty: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Room {
+    pub code: String,
+    pub classes: Vec<RoomClass>,
+}
+
+impl Room {
+    /// Splits a room code like `SJT502` into its building code and the rest of the
+    /// room number.
+    fn split_code(&self) -> (&str, &str) {
+        let code = self.code.as_str();
+
+        // Building codes like `AB1` end with a digit, so they are matched first.
+        if let Some((building, _)) = BUILDINGS
+            .iter()
+            .filter(|(building, _)| code.starts_with(building))
+            .max_by_key(|(building, _)| building.len())
+        {
+            return code.split_at(building.len());
+        }
+
+        let index = code
+            .find(|ch: char| ch.is_ascii_digit())
+            .unwrap_or(code.len());
+        code.split_at(index)
+    }
+
+    pub fn building(&self) -> String {
+        let (building_code, _) = self.split_code();
+
+        match BUILDINGS.iter().find(|(code, _)| *code == building_code) {
+            Some((code, name)) => format!("{} ({})", name, code),
+            None => building_code.to_string(),
+        }
+    }
+
+    pub fn floor(&self) -> String {
+        let (_, number) = self.split_code();
+        let number = number.trim_start_matches(['-', ' ']);
+        let digits = number
+            .chars()
+            .take_while(|ch| ch.is_ascii_digit())
+            .collect::<String>();
+
+        match number.chars().next() {
+            Some('G') | Some('g') => "Ground floor".to_string(),
+            // Room numbers end with two digits of the room on the floor, like 502 on the
+            // fifth floor and 1002 on the tenth.
+            Some(_) if digits.len() >= 3 => match digits[..digits.len() - 2].parse::<u32>() {
+                Ok(0) => "Ground floor".to_string(),
+                Ok(floor) => format!("Floor {}", floor),
+                Err(_) => "Unknown floor".to_string(),
+            },
+            Some(_) if number.len() < 3 => "Ground floor".to_string(),
+            _ => "Unknown floor".to_string(),
+        }
+    }
+
+    /// Weekly timings during which the room has a class.
+    pub fn occupied_timings(&self) -> Vec<SlotTiming> {
+        self.classes
+            .iter()
+            .flat_map(|class| slot_timings(&class.slot))
+            .collect()
+    }
+
+    /// Checks if the room has no class overlapping any of the given timings.
+    pub fn is_free_during(&self, timings: &[SlotTiming]) -> bool {
+        let occupied_timings = self.occupied_timings();
+
+        !timings.iter().any(|timing| {
+            occupied_timings
+                .iter()
+                .any(|occupied| occupied.overlaps(timing))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(code: &str) -> Room {
+        Room {
+            code: code.to_string(),
+            classes: vec![],
+        }
+    }
+
+    #[test]
+    fn reads_the_floor_before_the_last_two_digits() {
+        assert_eq!(room("SJT502").floor(), "Floor 5");
+        assert_eq!(room("SJT1002").floor(), "Floor 10");
+        assert_eq!(room("TT-715").floor(), "Floor 7");
+        assert_eq!(room("AB1 0205").floor(), "Floor 2");
+        assert_eq!(room("MB012").floor(), "Ground floor");
+        assert_eq!(room("SMVG04").floor(), "Ground floor");
+        assert_eq!(room("GDN12").floor(), "Ground floor");
+        assert_eq!(room("PRPLAB").floor(), "Unknown floor");
+    }
+
+    #[test]
+    fn names_known_buildings() {
+        assert_eq!(room("SJT502").building(), "Silver Jubilee Tower (SJT)");
+        assert_eq!(room("MGB101").building(), "Mahatma Gandhi Block (MGB)");
+        assert_eq!(room("MB101").building(), "Main Building (MB)");
+        assert_eq!(room("AB1205").building(), "Academic Block 1 (AB1)");
+        assert_eq!(room("AB1205").floor(), "Floor 2");
+        assert_eq!(room("XYZ101").building(), "XYZ");
+    }
+}
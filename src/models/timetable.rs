@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::slots::{slot_timings, SlotTiming};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TimetableEntry {
+    pub class_id: String,
+    pub course_code: String,
+    pub course_title: String,
+    pub course_type: String,
+    pub slot: String,
+    pub venue: String,
+    pub faculty: String,
+}
+
+impl TimetableEntry {
+    pub fn timings(&self) -> Vec<SlotTiming> {
+        slot_timings(&self.slot)
+    }
+}
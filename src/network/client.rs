@@ -0,0 +1,141 @@
+use eyre::{eyre, Result};
+use reqwest::header::{COOKIE, RANGE};
+
+use crate::{parser, state::Session, util::VTOP_BASE_URL};
+
+/// A thin wrapper around the HTTP client which attaches the VTOP session to every
+/// request.
+#[derive(Clone)]
+pub struct VtopClient {
+    http: reqwest::Client,
+}
+
+impl Default for VtopClient {
+    fn default() -> Self {
+        VtopClient {
+            http: reqwest::Client::builder()
+                .user_agent("vtop-cli")
+                .build()
+                .expect("Failed to build the HTTP client."),
+        }
+    }
+}
+
+impl VtopClient {
+    /// Opens a session from the cookie of a VTOP session logged in from a browser, like
+    /// `JSESSIONID=...; SERVERID=...`. The csrf token and authorized id of the session
+    /// are read from the VTOP content page.
+    pub async fn open_session(&self, cookie: &str) -> Result<Session> {
+        let cookie_value = |name: &str| {
+            cookie
+                .split(';')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let (jsessionid, serverid) = match (cookie_value("JSESSIONID"), cookie_value("SERVERID")) {
+            (Some(jsessionid), Some(serverid)) => (jsessionid, serverid),
+            _ => {
+                return Err(eyre!(
+                    "The VTOP cookie should have a JSESSIONID and a SERVERID, like \
+                     JSESSIONID=...; SERVERID=..."
+                ))
+            }
+        };
+
+        let html = self
+            .http
+            .get(format!("{}content", VTOP_BASE_URL))
+            .header(
+                COOKIE,
+                format!("JSESSIONID={}; SERVERID={}", jsessionid, serverid),
+            )
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let (csrf_token, authorized_id) = parser::parse_session_tokens(&html).ok_or_else(|| {
+            eyre!("The VTOP session of the cookie has expired, log in again in a browser.")
+        })?;
+
+        Ok(Session::LoggedIn {
+            serverid,
+            jsessionid,
+            csrf_token,
+            authorized_id,
+        })
+    }
+
+    /// Posts a form to `path` (relative to the VTOP base url) along with the csrf token
+    /// and authorized id of the session, and returns the response body. Fails if VTOP
+    /// sends the login page instead, so that an expired session isn't read as empty data.
+    pub async fn post_form(
+        &self,
+        session: &Session,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<String> {
+        let (cookie, csrf_token, authorized_id) = session_credentials(session)?;
+
+        let mut form = vec![("_csrf", csrf_token), ("authorizedID", authorized_id)];
+        form.extend_from_slice(params);
+
+        let response = self
+            .http
+            .post(format!("{}{}", VTOP_BASE_URL, path))
+            .header(COOKIE, cookie)
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let redirected_to_login = response.url().path().contains("login");
+        let html = response.text().await?;
+        if redirected_to_login || parser::is_login_page(&html) {
+            return Err(eyre!(
+                "The VTOP session has expired, log in again in a browser."
+            ));
+        }
+
+        Ok(html)
+    }
+
+    /// Fetches a file from an absolute url with the session cookies attached, starting
+    /// at byte `offset`. The response status is left for the caller to check, since a
+    /// server may ignore or reject the range.
+    pub async fn get_from(
+        &self,
+        session: &Session,
+        url: &str,
+        offset: u64,
+    ) -> Result<reqwest::Response> {
+        let (cookie, ..) = session_credentials(session)?;
+
+        let mut request = self.http.get(url).header(COOKIE, cookie);
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={}-", offset));
+        }
+
+        Ok(request.send().await?)
+    }
+}
+
+fn session_credentials(session: &Session) -> Result<(String, &str, &str)> {
+    match session {
+        Session::LoggedOut => Err(eyre!("Log in to VTOP first.")),
+        Session::LoggedIn {
+            serverid,
+            jsessionid,
+            csrf_token,
+            authorized_id,
+        } => Ok((
+            format!("JSESSIONID={}; SERVERID={}", jsessionid, serverid),
+            csrf_token,
+            authorized_id,
+        )),
+    }
+}
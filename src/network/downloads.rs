@@ -0,0 +1,509 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use eyre::{eyre, Result};
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::Semaphore,
+};
+
+use crate::{
+    app::App,
+    state::Session,
+    util::{sanitize_file_name, DOWNLOAD_PROGRESS_INTERVAL_MILLIS, MAXIMUM_CONCURRENT_DOWNLOADS},
+};
+
+use super::{response_file_name, VtopClient};
+
+const PART_FILE_EXTENSION: &str = "part";
+
+#[derive(Clone)]
+pub struct DownloadRequest {
+    pub url: String,
+    /// Directory relative to the configured download directory.
+    pub directory: PathBuf,
+    /// File name used when the server doesn't send one.
+    pub file_name: String,
+}
+
+#[derive(Clone)]
+pub enum DownloadStatus {
+    Queued,
+    Downloading,
+    Completed,
+    Duplicate(PathBuf),
+    Failed(String),
+    Cancelled,
+}
+
+impl DownloadStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, DownloadStatus::Queued | DownloadStatus::Downloading)
+    }
+}
+
+#[derive(Clone)]
+pub struct Download {
+    pub id: usize,
+    pub request: DownloadRequest,
+    pub directory: PathBuf,
+    pub path: Option<PathBuf>,
+    pub hash: Option<String>,
+    pub status: DownloadStatus,
+    pub downloaded_bytes: u64,
+    pub total_bytes: Option<u64>,
+    pub bytes_per_second: f64,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Download {
+    pub fn name(&self) -> String {
+        match &self.path {
+            Some(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            None => self.request.file_name.clone(),
+        }
+    }
+
+    pub fn status_text(&self) -> String {
+        match &self.status {
+            DownloadStatus::Queued => "Queued".to_string(),
+            DownloadStatus::Downloading => "Downloading".to_string(),
+            DownloadStatus::Completed => "Completed".to_string(),
+            DownloadStatus::Duplicate(_) => "Duplicate".to_string(),
+            DownloadStatus::Failed(_) => "Failed".to_string(),
+            DownloadStatus::Cancelled => "Cancelled".to_string(),
+        }
+    }
+
+    pub fn progress_text(&self) -> String {
+        match self.total_bytes {
+            Some(total) if total > 0 => format!(
+                "{:>3}% of {}",
+                self.downloaded_bytes * 100 / total,
+                format_bytes(total as f64)
+            ),
+            _ => format_bytes(self.downloaded_bytes as f64),
+        }
+    }
+
+    pub fn speed_text(&self) -> String {
+        match self.status {
+            DownloadStatus::Downloading => format!("{}/s", format_bytes(self.bytes_per_second)),
+            _ => String::new(),
+        }
+    }
+}
+
+enum DownloadOutcome {
+    Completed { path: PathBuf, hash: String },
+    Duplicate(PathBuf),
+    Cancelled,
+}
+
+/// Runs the downloads queued by the pages in the background, at most
+/// `MAXIMUM_CONCURRENT_DOWNLOADS` at a time, and keeps `VtopData::downloads` updated
+/// with their progress.
+pub struct DownloadManager {
+    app: Arc<tokio::sync::Mutex<App>>,
+    client: VtopClient,
+    permits: Arc<Semaphore>,
+}
+
+impl DownloadManager {
+    pub fn new(app: Arc<tokio::sync::Mutex<App>>, client: VtopClient) -> DownloadManager {
+        DownloadManager {
+            app,
+            client,
+            permits: Arc::new(Semaphore::new(MAXIMUM_CONCURRENT_DOWNLOADS)),
+        }
+    }
+
+    pub async fn queue(&self, requests: Vec<DownloadRequest>) {
+        let mut ids = vec![];
+
+        {
+            let mut app = self.app.lock().await;
+            let download_directory = app.config.download_directory.clone();
+
+            for request in requests {
+                let id = app.state.data.downloads.len();
+                app.state.data.downloads.push(Download {
+                    id,
+                    directory: download_directory.join(&request.directory),
+                    request,
+                    path: None,
+                    hash: None,
+                    status: DownloadStatus::Queued,
+                    downloaded_bytes: 0,
+                    total_bytes: None,
+                    bytes_per_second: 0.0,
+                    cancelled: Arc::new(AtomicBool::new(false)),
+                });
+                ids.push(id);
+            }
+
+            app.state
+                .dispatch_notification(format!("Queued {} downloads.", ids.len()));
+        }
+
+        for id in ids {
+            self.spawn(id);
+        }
+    }
+
+    /// Queues a failed or cancelled download again. It resumes from the partially
+    /// downloaded file if the server supports it.
+    pub async fn retry(&self, id: usize) {
+        {
+            let mut app = self.app.lock().await;
+            let download = match app.state.data.downloads.get_mut(id) {
+                Some(download) => download,
+                None => return,
+            };
+
+            if !matches!(
+                download.status,
+                DownloadStatus::Failed(_) | DownloadStatus::Cancelled
+            ) {
+                return;
+            }
+
+            download.status = DownloadStatus::Queued;
+            download.cancelled = Arc::new(AtomicBool::new(false));
+        }
+
+        self.spawn(id);
+    }
+
+    pub async fn cancel(&self, id: usize) {
+        let mut app = self.app.lock().await;
+
+        if let Some(download) = app.state.data.downloads.get_mut(id) {
+            if !download.status.is_finished() {
+                download.cancelled.store(true, Ordering::Relaxed);
+                download.status = DownloadStatus::Cancelled;
+                download.bytes_per_second = 0.0;
+            }
+        }
+    }
+
+    fn spawn(&self, id: usize) {
+        let app = Arc::clone(&self.app);
+        let client = self.client.clone();
+        let permits = Arc::clone(&self.permits);
+
+        tokio::spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            run_download(app, client, id).await;
+        });
+    }
+}
+
+async fn run_download(app_mutex: Arc<tokio::sync::Mutex<App>>, client: VtopClient, id: usize) {
+    let (session, request, directory, cancelled, known_hashes) = {
+        let mut app = app_mutex.lock().await;
+        let session = app.state.session.clone();
+        let known_hashes = app
+            .state
+            .data
+            .downloads
+            .iter()
+            .filter_map(|download| Some((download.hash.clone()?, download.path.clone()?)))
+            .collect::<Vec<(String, PathBuf)>>();
+
+        let download = match app.state.data.downloads.get_mut(id) {
+            Some(download) => download,
+            None => return,
+        };
+
+        if download.cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        download.status = DownloadStatus::Downloading;
+
+        (
+            session,
+            download.request.clone(),
+            download.directory.clone(),
+            Arc::clone(&download.cancelled),
+            known_hashes,
+        )
+    };
+
+    let result: Result<DownloadOutcome> = async {
+        fs::create_dir_all(&directory).await?;
+        let part_path = directory.join(part_file_name(&request.url));
+
+        let file_name = download_to(
+            &app_mutex,
+            &client,
+            &session,
+            id,
+            &request.url,
+            &part_path,
+            &cancelled,
+        )
+        .await?
+        .unwrap_or(request.file_name);
+
+        if cancelled.load(Ordering::Relaxed) {
+            return Ok(DownloadOutcome::Cancelled);
+        }
+
+        let hash = file_hash(&part_path).await?;
+
+        if let Some(existing_path) =
+            find_duplicate(&directory, &part_path, &hash, &known_hashes).await?
+        {
+            fs::remove_file(&part_path).await?;
+            return Ok(DownloadOutcome::Duplicate(existing_path));
+        }
+
+        let path = unique_path(&directory.join(sanitize_file_name(&file_name)));
+        fs::rename(&part_path, &path).await?;
+
+        Ok(DownloadOutcome::Completed { path, hash })
+    }
+    .await;
+
+    let mut app = app_mutex.lock().await;
+    let mut failed_download = None;
+
+    // A retried download replaces the cancellation flag, so a stale attempt must not
+    // overwrite the status of the new one.
+    if let Some(download) = app
+        .state
+        .data
+        .downloads
+        .get_mut(id)
+        .filter(|download| Arc::ptr_eq(&download.cancelled, &cancelled))
+    {
+        download.bytes_per_second = 0.0;
+
+        match result {
+            Ok(DownloadOutcome::Completed { path, hash }) => {
+                download.status = DownloadStatus::Completed;
+                download.path = Some(path);
+                download.hash = Some(hash);
+            }
+            Ok(DownloadOutcome::Duplicate(existing_path)) => {
+                download.status = DownloadStatus::Duplicate(existing_path);
+            }
+            Ok(DownloadOutcome::Cancelled) => download.status = DownloadStatus::Cancelled,
+            Err(e) => {
+                download.status = DownloadStatus::Failed(e.to_string());
+                failed_download = Some(download.name());
+            }
+        }
+    }
+
+    if let Some(name) = failed_download {
+        app.state
+            .dispatch_notification(format!("Failed to download {}.", name));
+    }
+    app.refresh_pages();
+}
+
+/// Downloads `url` into `part_path`, continuing from the end of the file if a previous
+/// attempt left it partially downloaded. Returns the file name sent by the server.
+async fn download_to(
+    app_mutex: &Arc<tokio::sync::Mutex<App>>,
+    client: &VtopClient,
+    session: &Session,
+    id: usize,
+    url: &str,
+    part_path: &Path,
+    cancelled: &AtomicBool,
+) -> Result<Option<String>> {
+    let resume_from = fs::metadata(part_path)
+        .await
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+
+    let mut response = client.get_from(session, url, resume_from).await?;
+
+    if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        // The partial file is either complete or doesn't match the remote file anymore.
+        fs::remove_file(part_path).await?;
+        response = client.get_from(session, url, 0).await?;
+    }
+
+    let response_status = response.status();
+    if !response_status.is_success() {
+        return Err(eyre!("Server responded with {}", response_status));
+    }
+
+    let file_name = response_file_name(&response);
+    let resumed = response_status == StatusCode::PARTIAL_CONTENT;
+    let mut downloaded_bytes = if resumed { resume_from } else { 0 };
+    let total_bytes = response
+        .content_length()
+        .map(|length| length + downloaded_bytes);
+
+    let mut file = if resumed {
+        fs::OpenOptions::new().append(true).open(part_path).await?
+    } else {
+        fs::File::create(part_path).await?
+    };
+
+    let started = Instant::now();
+    let mut last_update = Instant::now();
+    let mut session_bytes = 0;
+
+    while let Some(chunk) = response.chunk().await? {
+        if cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        file.write_all(&chunk).await?;
+        downloaded_bytes += chunk.len() as u64;
+        session_bytes += chunk.len() as u64;
+
+        if last_update.elapsed().as_millis() >= DOWNLOAD_PROGRESS_INTERVAL_MILLIS {
+            last_update = Instant::now();
+
+            let mut app = app_mutex.lock().await;
+            if let Some(download) = app.state.data.downloads.get_mut(id) {
+                download.downloaded_bytes = downloaded_bytes;
+                download.total_bytes = total_bytes;
+                download.bytes_per_second =
+                    session_bytes as f64 / started.elapsed().as_secs_f64().max(0.001);
+            }
+            app.refresh_pages();
+        }
+    }
+
+    file.flush().await?;
+
+    let mut app = app_mutex.lock().await;
+    if let Some(download) = app.state.data.downloads.get_mut(id) {
+        download.downloaded_bytes = downloaded_bytes;
+        download.total_bytes = total_bytes;
+    }
+
+    Ok(file_name)
+}
+
+async fn file_hash(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// Name of the hidden file a download is written to until it completes. It is named by
+/// the hash of the URL, so that downloads of files with the same name don't share it
+/// and a retried download resumes it.
+fn part_file_name(url: &str) -> String {
+    let url_hash: String = Sha256::digest(url.as_bytes())
+        .iter()
+        .take(8)
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    format!(".{}.{}", url_hash, PART_FILE_EXTENSION)
+}
+
+/// Looks for a file with the same content as the downloaded file, first among the
+/// finished downloads and then among the files of the destination directory.
+async fn find_duplicate(
+    directory: &Path,
+    part_path: &Path,
+    hash: &str,
+    known_hashes: &[(String, PathBuf)],
+) -> Result<Option<PathBuf>> {
+    if let Some((_, path)) = known_hashes
+        .iter()
+        .find(|(known_hash, path)| known_hash == hash && path.exists())
+    {
+        return Ok(Some(path.clone()));
+    }
+
+    let size = fs::metadata(part_path).await?.len();
+    let mut checked_paths = HashSet::new();
+    let mut entries = fs::read_dir(directory).await?;
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let metadata = entry.metadata().await?;
+
+        // Other downloads may still be writing to their partial files.
+        let is_part_file = path
+            .extension()
+            .is_some_and(|extension| extension == PART_FILE_EXTENSION);
+        if is_part_file || !metadata.is_file() || metadata.len() != size {
+            continue;
+        }
+
+        if checked_paths.insert(path.clone()) && file_hash(&path).await? == hash {
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Appends a counter to the file name if a file already exists at `path`.
+fn unique_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+
+    (1..)
+        .map(|counter| path.with_file_name(format!("{} ({}){}", stem, counter, extension)))
+        .find(|path| !path.exists())
+        .unwrap()
+}
+
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", value as u64, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,821 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local};
+use eyre::{eyre, Result};
+
+use crate::{
+    alerts,
+    app::App,
+    export::{self, DataTable},
+    models::{
+        attendance_changes, class_message_changes, grades_changes, marks_changes, same_day_exams,
+        spotlight_changes, AcademicCalendar, Change, DataKind,
+    },
+    parser,
+    state::Session,
+    storage,
+    util::sanitize_file_name,
+};
+
+pub use client::VtopClient;
+pub use downloads::{Download, DownloadManager, DownloadRequest, DownloadStatus};
+
+mod client;
+mod downloads;
+
+/// Events fetching semester wise data take `None` as the semester id to fetch the data
+/// of the global semester.
+pub enum NetworkEvent {
+    /// Logs in with the cookie of a VTOP session logged in from a browser.
+    LogIn {
+        cookie: String,
+    },
+    GetSemesters,
+    GetTimetable {
+        semester_id: Option<String>,
+    },
+    GetAttendance {
+        semester_id: Option<String>,
+    },
+    GetMarks {
+        semester_id: Option<String>,
+    },
+    GetGrades {
+        semester_id: Option<String>,
+    },
+    GetExamSchedule {
+        semester_id: Option<String>,
+    },
+    /// Fetches every month of the semester's academic calendar.
+    GetAcademicCalendar {
+        semester_id: Option<String>,
+    },
+    /// Writes the semester's time table to an iCalendar file in the download directory,
+    /// fetching the time table and academic calendar first if needed.
+    ExportTimetableCalendar {
+        semester_id: Option<String>,
+    },
+    /// Syncs the semester's time table and exam schedule to the CalDAV collection in the
+    /// config, fetching them first if needed.
+    SyncCalendar {
+        semester_id: Option<String>,
+    },
+    /// Writes a table of the semester's data to a file in the download directory, in the
+    /// export format of the config, fetching the data first if needed.
+    ExportTable {
+        table: DataTable,
+        semester_id: Option<String>,
+    },
+    GetClassMessages,
+    GetSpotlight,
+    /// Fetches everything shown on the home page.
+    RefreshDashboard,
+    GetCoursePageCourses {
+        semester_id: Option<String>,
+    },
+    GetCourseMaterials {
+        class_id: String,
+    },
+    GetRoomInformation {
+        room_code: String,
+    },
+    QueueDownloads {
+        requests: Vec<DownloadRequest>,
+    },
+    RetryDownload {
+        id: usize,
+    },
+    CancelDownload {
+        id: usize,
+    },
+}
+
+impl NetworkEvent {
+    /// Whether the event can change the data fetched from VTOP, which is then cached.
+    fn changes_data(&self) -> bool {
+        !matches!(
+            self,
+            NetworkEvent::LogIn { .. }
+                | NetworkEvent::QueueDownloads { .. }
+                | NetworkEvent::RetryDownload { .. }
+                | NetworkEvent::CancelDownload { .. }
+        )
+    }
+}
+
+pub struct NetworkHandler<'a> {
+    pub app: &'a Arc<tokio::sync::Mutex<App>>,
+    pub client: VtopClient,
+    pub downloads: DownloadManager,
+}
+
+impl<'a> NetworkHandler<'a> {
+    pub fn new(app: &'a Arc<tokio::sync::Mutex<App>>) -> NetworkHandler<'a> {
+        let client = VtopClient::default();
+
+        NetworkHandler {
+            app,
+            downloads: DownloadManager::new(Arc::clone(app), client.clone()),
+            client,
+        }
+    }
+
+    /// Runs the event, then shows its error as a notification and refreshes the pages.
+    pub async fn handle_network_event(&mut self, network_event: NetworkEvent) {
+        let result = self.run_network_event(network_event).await;
+
+        let mut app = self.app.lock().await;
+        if let Err(e) = result {
+            app.state.dispatch_notification(e.to_string());
+        }
+        app.refresh_pages();
+        app.loaded();
+    }
+
+    /// Runs the event and returns its error, leaving the pages alone. The data is cached
+    /// after every successful event which can change it, and failing to reach VTOP marks
+    /// the app offline.
+    pub async fn run_network_event(&mut self, network_event: NetworkEvent) -> Result<()> {
+        let changes_data = network_event.changes_data();
+        let started_at = Local::now();
+        let result = self.run(network_event).await;
+        self.raise_alerts(started_at).await;
+
+        let data = {
+            let mut app = self.app.lock().await;
+            match &result {
+                Ok(()) => {
+                    app.state.connection_lost = false;
+                    changes_data.then(|| app.state.data.clone())
+                }
+                Err(e) => {
+                    app.state.connection_lost = is_connection_error(e);
+                    None
+                }
+            }
+        };
+
+        // The cache is written from a snapshot, so that the app isn't locked meanwhile.
+        if let Some(data) = data {
+            let saved = tokio::task::spawn_blocking(move || storage::save(&data))
+                .await
+                .map_err(eyre::Report::from)
+                .and_then(|saved| saved);
+            if let Err(e) = saved {
+                self.app
+                    .lock()
+                    .await
+                    .state
+                    .dispatch_notification(format!("Failed to cache the data: {}", e));
+            }
+        }
+
+        result
+    }
+
+    /// Checks the changes found since the event started against the alert rules, and
+    /// runs the actions of the matching ones.
+    async fn raise_alerts(&mut self, started_at: DateTime<Local>) {
+        let alerts = {
+            let mut app = self.app.lock().await;
+            let changes: Vec<Change> = app
+                .state
+                .data
+                .changes
+                .iter()
+                .filter(|change| change.detected_at >= started_at)
+                .cloned()
+                .collect();
+
+            let alerts = alerts::matching_alerts(&app.config.alerts, &changes);
+            for alert in alerts.iter().filter(|alert| alert.rule.notify) {
+                app.state.dispatch_notification(alert.message());
+            }
+            alerts
+        };
+
+        for alert in alerts {
+            if let Err(e) = alert.run_actions().await {
+                self.app
+                    .lock()
+                    .await
+                    .state
+                    .dispatch_notification(e.to_string());
+            }
+        }
+    }
+
+    async fn run(&mut self, network_event: NetworkEvent) -> Result<()> {
+        match network_event {
+            NetworkEvent::LogIn { cookie } => self.log_in(cookie).await,
+            NetworkEvent::GetSemesters => self.get_semesters().await,
+            NetworkEvent::GetTimetable { semester_id } => self.get_timetable(semester_id).await,
+            NetworkEvent::GetAttendance { semester_id } => self.get_attendance(semester_id).await,
+            NetworkEvent::GetMarks { semester_id } => self.get_marks(semester_id).await,
+            NetworkEvent::GetGrades { semester_id } => self.get_grades(semester_id).await,
+            NetworkEvent::GetExamSchedule { semester_id } => {
+                self.get_exam_schedule(semester_id).await
+            }
+            NetworkEvent::GetAcademicCalendar { semester_id } => {
+                self.get_academic_calendar(semester_id).await
+            }
+            NetworkEvent::ExportTimetableCalendar { semester_id } => {
+                self.export_timetable_calendar(semester_id).await
+            }
+            NetworkEvent::SyncCalendar { semester_id } => self.sync_calendar(semester_id).await,
+            NetworkEvent::ExportTable { table, semester_id } => {
+                self.export_table(table, semester_id).await
+            }
+            NetworkEvent::GetClassMessages => self.get_class_messages().await,
+            NetworkEvent::GetSpotlight => self.get_spotlight().await,
+            NetworkEvent::RefreshDashboard => self.refresh_dashboard().await,
+            NetworkEvent::GetCoursePageCourses { semester_id } => {
+                self.get_course_page_courses(semester_id).await
+            }
+            NetworkEvent::GetCourseMaterials { class_id } => {
+                self.get_course_materials(class_id).await
+            }
+            NetworkEvent::GetRoomInformation { room_code } => {
+                self.get_room_information(room_code).await
+            }
+            NetworkEvent::QueueDownloads { requests } => {
+                self.downloads.queue(requests).await;
+                Ok(())
+            }
+            NetworkEvent::RetryDownload { id } => {
+                self.downloads.retry(id).await;
+                Ok(())
+            }
+            NetworkEvent::CancelDownload { id } => {
+                self.downloads.cancel(id).await;
+                Ok(())
+            }
+        }
+    }
+
+    async fn session(&self) -> Session {
+        self.app.lock().await.state.session.clone()
+    }
+
+    async fn log_in(&mut self, cookie: String) -> Result<()> {
+        let session = self.client.open_session(&cookie).await?;
+        self.app.lock().await.state.session = session;
+
+        Ok(())
+    }
+
+    async fn get_semesters(&mut self) -> Result<()> {
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "academics/common/StudentTimeTable",
+                &[("verifyMenu", "true")],
+            )
+            .await?;
+
+        let semesters = parser::parse_semesters(&html);
+
+        let mut app = self.app.lock().await;
+        app.state.data.semesters = semesters;
+        app.state.data.mark_fetched(DataKind::Semesters, "");
+
+        Ok(())
+    }
+
+    /// Returns the given semester id, or the id of the global semester if it is `None`.
+    async fn resolve_semester(&mut self, semester_id: Option<String>) -> Result<String> {
+        if let Some(semester_id) = semester_id {
+            return Ok(semester_id);
+        }
+
+        if self.app.lock().await.state.data.semesters.is_empty() {
+            self.get_semesters().await?;
+        }
+
+        let app = self.app.lock().await;
+        app.state
+            .data
+            .global_semester()
+            .map(|semester| semester.id.clone())
+            .ok_or_else(|| eyre!("No semesters found on VTOP."))
+    }
+
+    async fn get_timetable(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "processViewTimeTable",
+                &[("semesterSubId", &semester_id)],
+            )
+            .await?;
+
+        let timetable = parser::parse_timetable(&html);
+
+        let mut app = self.app.lock().await;
+        app.state
+            .data
+            .mark_fetched(DataKind::Timetable, &semester_id);
+        app.state.data.timetable.insert(semester_id, timetable);
+
+        Ok(())
+    }
+
+    async fn get_attendance(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "processViewStudentAttendance",
+                &[("semesterSubId", &semester_id)],
+            )
+            .await?;
+
+        let attendance = parser::parse_attendance(&html);
+
+        let mut app = self.app.lock().await;
+        if let Some(old) = app.state.data.attendance.get(&semester_id) {
+            let changes = attendance_changes(old, &attendance);
+            app.state.record_changes(changes);
+        }
+        app.state
+            .data
+            .mark_fetched(DataKind::Attendance, &semester_id);
+        app.state.data.attendance.insert(semester_id, attendance);
+
+        Ok(())
+    }
+
+    async fn get_marks(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "examinations/doStudentMarkView",
+                &[("semesterSubId", &semester_id)],
+            )
+            .await?;
+
+        let marks = parser::parse_marks(&html);
+
+        let mut app = self.app.lock().await;
+        if let Some(old) = app.state.data.marks.get(&semester_id) {
+            let changes = marks_changes(old, &marks);
+            app.state.record_changes(changes);
+        }
+        app.state.data.mark_fetched(DataKind::Marks, &semester_id);
+        app.state.data.marks.insert(semester_id, marks);
+
+        Ok(())
+    }
+
+    async fn get_grades(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "examinations/examGradeView/doStudentGradeView",
+                &[("semesterSubId", &semester_id)],
+            )
+            .await?;
+
+        let grades = parser::parse_grades(&html);
+
+        let mut app = self.app.lock().await;
+        if let Some(old) = app.state.data.grades.get(&semester_id) {
+            let changes = grades_changes(old, &grades);
+            app.state.record_changes(changes);
+        }
+        app.state.data.mark_fetched(DataKind::Grades, &semester_id);
+        app.state.data.grades.insert(semester_id, grades);
+
+        Ok(())
+    }
+
+    async fn get_exam_schedule(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "examinations/doSearchExamScheduleForStudent",
+                &[("semesterSubId", &semester_id)],
+            )
+            .await?;
+
+        let exam_schedule = parser::parse_exam_schedule(&html);
+
+        let mut app = self.app.lock().await;
+
+        // Every day is notified about once, or again if its exams change.
+        let today = Local::now().date_naive();
+        for (date, exams) in same_day_exams(&exam_schedule, today) {
+            let exams = exams
+                .iter()
+                .map(|exam| format!("{} {}", exam.exam_type, exam.course_code))
+                .collect::<Vec<String>>();
+
+            let key = format!("{}|{}", date, exams.join("|"));
+            if !app.state.data.notified_same_day_exams.insert(key) {
+                continue;
+            }
+
+            app.state.dispatch_notification(format!(
+                "{} are on the same day, {}.",
+                exams.join(", "),
+                date.format("%d-%b-%Y")
+            ));
+        }
+
+        app.state
+            .data
+            .mark_fetched(DataKind::ExamSchedule, &semester_id);
+        app.state
+            .data
+            .exam_schedule
+            .insert(semester_id, exam_schedule);
+
+        Ok(())
+    }
+
+    async fn get_academic_calendar(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "academics/common/CalendarPreview",
+                &[("semSubId", &semester_id)],
+            )
+            .await?;
+
+        let mut calendar = AcademicCalendar {
+            months: parser::parse_calendar_months(&html),
+            ..AcademicCalendar::default()
+        };
+
+        for month in calendar.months.clone() {
+            let cal_date = month.format("%d-%b-%Y").to_string().to_uppercase();
+            let html = self
+                .client
+                .post_form(
+                    &session,
+                    "academics/common/CalendarContent",
+                    &[
+                        ("calDate", &cal_date),
+                        ("semSubId", &semester_id),
+                        ("classGroupId", "ALL"),
+                    ],
+                )
+                .await?;
+
+            for day in parser::parse_calendar_month(&html, month) {
+                calendar.days.insert(day.date, day);
+            }
+        }
+
+        let mut app = self.app.lock().await;
+        app.state
+            .data
+            .mark_fetched(DataKind::AcademicCalendar, &semester_id);
+        app.state
+            .data
+            .academic_calendar
+            .insert(semester_id, calendar);
+
+        Ok(())
+    }
+
+    async fn export_timetable_calendar(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+
+        let result = self.get_timetable(Some(semester_id.clone())).await;
+        self.or_cached(result, DataKind::Timetable, &semester_id)
+            .await?;
+        let result = self.get_academic_calendar(Some(semester_id.clone())).await;
+        self.or_cached(result, DataKind::AcademicCalendar, &semester_id)
+            .await?;
+
+        let (events, calendar, directory, path) = {
+            let app = self.app.lock().await;
+            let data = &app.state.data;
+
+            let name = format!("Time Table {}", data.semester_name(&semester_id));
+            let events = export::timetable_events(
+                &data.timetable[&semester_id],
+                &data.academic_calendar[&semester_id],
+            )?;
+            let calendar = export::write_calendar(&name, &events);
+
+            let directory = app.config.download_directory.clone();
+            let path = directory.join(format!("{}.ics", sanitize_file_name(&name)));
+
+            (events, calendar, directory, path)
+        };
+
+        // The file is written after letting go of the app, so that the UI isn't blocked
+        // by the disk.
+        tokio::fs::create_dir_all(&directory).await?;
+        tokio::fs::write(&path, calendar).await?;
+
+        self.app.lock().await.state.dispatch_notification(format!(
+            "Exported {} classes to {}.",
+            events.len(),
+            path.display()
+        ));
+
+        Ok(())
+    }
+
+    async fn export_table(&mut self, table: DataTable, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+
+        let semester = Some(semester_id.clone());
+        let result = match table {
+            DataTable::Timetable => self.get_timetable(semester).await,
+            DataTable::Attendance => self.get_attendance(semester).await,
+            DataTable::Marks => self.get_marks(semester).await,
+            DataTable::Grades => self.get_grades(semester).await,
+            DataTable::ExamSchedule => self.get_exam_schedule(semester).await,
+        };
+        self.or_cached(result, table.kind(), &semester_id).await?;
+
+        let (name, contents, directory, path) = {
+            let app = self.app.lock().await;
+            let format = app.config.export_format;
+            let data = &app.state.data;
+
+            let name = format!("{} {}", table.name(), data.semester_name(&semester_id));
+            let contents = table
+                .table(data, &semester_id)
+                .ok_or_else(|| eyre!("No {} to export.", table.name().to_lowercase()))?
+                .write(format);
+
+            let directory = app.config.download_directory.clone();
+            let path = directory.join(format!(
+                "{}.{}",
+                sanitize_file_name(&name),
+                format.extension()
+            ));
+
+            (name, contents, directory, path)
+        };
+
+        tokio::fs::create_dir_all(&directory).await?;
+        tokio::fs::write(&path, contents).await?;
+
+        self.app.lock().await.state.dispatch_notification(format!(
+            "Exported {} to {}.",
+            name,
+            path.display()
+        ));
+
+        Ok(())
+    }
+
+    /// Passes on the result of fetching data again before exporting it, unless the fetch
+    /// failed while the data is cached, in which case the cached data is used instead.
+    async fn or_cached(&self, result: Result<()>, kind: DataKind, key: &str) -> Result<()> {
+        let error = match result {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+
+        let mut app = self.app.lock().await;
+        match app.state.data.fetched_at(kind, key) {
+            Some(fetched_at) => {
+                app.state.dispatch_notification(format!(
+                    "{} Using the data cached at {}.",
+                    error,
+                    fetched_at.format("%d-%b-%Y %H:%M")
+                ));
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    async fn sync_calendar(&mut self, semester_id: Option<String>) -> Result<()> {
+        let caldav = self
+            .app
+            .lock()
+            .await
+            .config
+            .caldav
+            .clone()
+            .ok_or_else(|| eyre!("Add a [caldav] collection to the config to sync with."))?;
+
+        let semester_id = self.resolve_semester(semester_id).await?;
+
+        // The data is always fetched again, so that the events of a cache loaded from an
+        // earlier run aren't pushed if VTOP has changed since.
+        let result = self.get_timetable(Some(semester_id.clone())).await;
+        self.or_cached(result, DataKind::Timetable, &semester_id)
+            .await?;
+        let result = self.get_academic_calendar(Some(semester_id.clone())).await;
+        self.or_cached(result, DataKind::AcademicCalendar, &semester_id)
+            .await?;
+        let result = self.get_exam_schedule(Some(semester_id.clone())).await;
+        self.or_cached(result, DataKind::ExamSchedule, &semester_id)
+            .await?;
+
+        let (name, events) = {
+            let data = &self.app.lock().await.state.data;
+
+            let semester_name = data.semester_name(&semester_id);
+
+            let mut events = export::timetable_events(
+                &data.timetable[&semester_id],
+                &data.academic_calendar[&semester_id],
+            )?;
+            events.extend(export::exam_schedule_events(
+                &data.exam_schedule[&semester_id],
+            ));
+
+            (semester_name, events)
+        };
+
+        let summary = export::sync_calendar(&caldav, &semester_id, &name, &events).await?;
+
+        self.app.lock().await.state.dispatch_notification(format!(
+            "Synced {} events to {}, removed {} old ones.",
+            summary.updated, caldav.url, summary.deleted
+        ));
+
+        Ok(())
+    }
+
+    async fn get_class_messages(&mut self) -> Result<()> {
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(&session, "academics/common/StudentClassMessage", &[])
+            .await?;
+
+        let class_messages = parser::parse_class_messages(&html);
+
+        let mut app = self.app.lock().await;
+        if app
+            .state
+            .data
+            .fetched_at(DataKind::ClassMessages, "")
+            .is_some()
+        {
+            let changes = class_message_changes(&app.state.data.class_messages, &class_messages);
+            app.state.record_changes(changes);
+        }
+        app.state.data.class_messages = class_messages;
+        app.state.data.mark_fetched(DataKind::ClassMessages, "");
+
+        Ok(())
+    }
+
+    async fn get_spotlight(&mut self) -> Result<()> {
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(&session, "spotlight/getSpotlight", &[])
+            .await?;
+
+        let spotlight = parser::parse_spotlight(&html);
+
+        let mut app = self.app.lock().await;
+        if app.state.data.fetched_at(DataKind::Spotlight, "").is_some() {
+            let changes = spotlight_changes(&app.state.data.spotlight, &spotlight);
+            app.state.record_changes(changes);
+        }
+        app.state.data.spotlight = spotlight;
+        app.state.data.mark_fetched(DataKind::Spotlight, "");
+
+        Ok(())
+    }
+
+    /// Fetches every card of the home page, stopping at the first failure since it is
+    /// most likely shared by the rest (like an expired session).
+    async fn refresh_dashboard(&mut self) -> Result<()> {
+        let semester_id = self.resolve_semester(None).await?;
+
+        self.get_timetable(Some(semester_id.clone())).await?;
+        self.get_attendance(Some(semester_id.clone())).await?;
+        self.get_marks(Some(semester_id.clone())).await?;
+        self.get_class_messages().await?;
+        self.get_spotlight().await?;
+        self.get_academic_calendar(Some(semester_id)).await
+    }
+
+    async fn get_course_page_courses(&mut self, semester_id: Option<String>) -> Result<()> {
+        let semester_id = self.resolve_semester(semester_id).await?;
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "getCourseForCoursePage",
+                &[
+                    ("paramReturnId", "getCourseForCoursePage"),
+                    ("semSubId", &semester_id),
+                ],
+            )
+            .await?;
+
+        let courses = parser::parse_courses(&html);
+
+        let mut app = self.app.lock().await;
+        app.state
+            .data
+            .mark_fetched(DataKind::CoursePageCourses, &semester_id);
+        app.state
+            .data
+            .course_page
+            .courses
+            .insert(semester_id, courses);
+
+        Ok(())
+    }
+
+    async fn get_course_materials(&mut self, class_id: String) -> Result<()> {
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "processViewStudentCourseDetail",
+                &[("classId", &class_id)],
+            )
+            .await?;
+
+        let materials = parser::parse_course_materials(&html);
+
+        let mut app = self.app.lock().await;
+        app.state
+            .data
+            .mark_fetched(DataKind::CourseMaterials, &class_id);
+        app.state
+            .data
+            .course_page
+            .materials
+            .insert(class_id, materials);
+
+        Ok(())
+    }
+
+    async fn get_room_information(&mut self, room_code: String) -> Result<()> {
+        let session = self.session().await;
+        let html = self
+            .client
+            .post_form(
+                &session,
+                "academics/common/RoomInformation",
+                &[("roomNo", &room_code)],
+            )
+            .await?;
+
+        let rooms = parser::parse_room_schedules(&html);
+
+        let mut app = self.app.lock().await;
+        if rooms.is_empty() {
+            app.state
+                .dispatch_notification(format!("No room found for {}.", room_code));
+        }
+        for room in rooms {
+            app.state.data.mark_fetched(DataKind::Room, &room.code);
+            app.state.data.rooms.insert(room.code.clone(), room);
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the error is a failure to reach VTOP at all, rather than an error response.
+fn is_connection_error(error: &eyre::Report) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|error| error.is_connect() || error.is_timeout())
+}
+
+/// Returns the file name from the `Content-Disposition` header of a response.
+fn response_file_name(response: &reqwest::Response) -> Option<String> {
+    let disposition = response
+        .headers()
+        .get(reqwest::header::CONTENT_DISPOSITION)?
+        .to_str()
+        .ok()?;
+
+    disposition
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("filename="))
+        .map(|name| name.trim_matches('"').to_string())
+        .filter(|name| !name.is_empty())
+}
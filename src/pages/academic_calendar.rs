@@ -0,0 +1,315 @@
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::{AcademicCalendar, DayKind, VtopData},
+    network::NetworkEvent,
+};
+
+use super::{
+    handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockLayout,
+    BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const MONTHS_BLOCK: usize = 1;
+const DAYS_BLOCK: usize = 2;
+const DETAILS_BLOCK: usize = 3;
+
+pub struct AcademicCalendarPage {}
+
+impl AcademicCalendarPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let months = Block::default("Month".to_string(), BlockType::ListBlock);
+        let days = Block::default("Calendar".to_string(), BlockType::ListBlock);
+        let details = Block::default("Day".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(months).unwrap();
+        block.append_inner_block(days).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(
+            semester_page_layout(vec![Constraint::Min(10), Constraint::Length(6)]).nest(
+                1,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(75),
+                ]),
+            ),
+        );
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetAcademicCalendar {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    /// The days of the month are laid out as a calendar, so the arrow keys move by a day
+    /// sideways and by a week up and down.
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+        let previous_month = chosen_month(block, data);
+
+        let step: Option<i64> = match key {
+            Key::Left => Some(-1),
+            Key::Right => Some(1),
+            Key::Up => Some(-7),
+            Key::Down => Some(7),
+            _ => None,
+        };
+
+        match (block.selected_block(), step) {
+            (Some(DAYS_BLOCK), Some(step)) => {
+                if let Some(days) = block.get_inner_block_mut(DAYS_BLOCK) {
+                    let hovered_day = days.hovered_item().unwrap_or(0) as i64;
+                    days.set_hovered_item((hovered_day + step).max(0) as usize);
+                }
+            }
+            _ => {
+                handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+                block.handle_input(key);
+            }
+        }
+
+        AcademicCalendarPage::refresh(block, data);
+
+        // A newly chosen month starts with today hovered, or its first day.
+        let month = chosen_month(block, data);
+        if month != previous_month {
+            let today = Local::now().date_naive();
+            if let (Some(month), Some(days)) = (month, block.get_inner_block_mut(DAYS_BLOCK)) {
+                days.set_hovered_item(if today.with_day(1) == Some(month) {
+                    today.day0() as usize
+                } else {
+                    0
+                });
+            }
+        }
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            if let Some(months) = block.get_inner_block_mut(MONTHS_BLOCK) {
+                months.set_chosen_item(None);
+            }
+            return AcademicCalendarPage::missing_data(block, data);
+        }
+
+        vec![]
+    }
+
+    /// Fetches the calendar of the selected semester if it isn't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.academic_calendar.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetAcademicCalendar {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let months = calendar(block, data)
+            .map(|calendar| {
+                calendar
+                    .months
+                    .iter()
+                    .map(|month| month.format("%B %Y").to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let Some(months_block) = block.get_inner_block_mut(MONTHS_BLOCK) {
+            months_block.set_list_items(months);
+        }
+
+        let days = match (chosen_month(block, data), calendar(block, data)) {
+            (Some(month), Some(calendar)) => month_days(month)
+                .into_iter()
+                .map(|date| {
+                    calendar
+                        .days
+                        .get(&date)
+                        .map(|day| day.description.clone())
+                        .unwrap_or_default()
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        if let Some(days_block) = block.get_inner_block_mut(DAYS_BLOCK) {
+            days_block.set_list_items(days);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+        let data = &app.state.data;
+
+        let hovered_date = hovered_date(block_self, data);
+        let month_lines = match (chosen_month(block_self, data), calendar(block_self, data)) {
+            (Some(month), Some(calendar)) => month_lines(month, calendar, hovered_date),
+            _ => vec![],
+        };
+
+        let details = match (hovered_date, calendar(block_self, data)) {
+            (Some(date), Some(calendar)) => {
+                let mut lines = vec![Spans::from(Span::styled(
+                    date.format("%A, %d %B %Y").to_string(),
+                    Style::default()
+                        .fg(Color::LightCyan)
+                        .add_modifier(Modifier::BOLD),
+                ))];
+
+                if let Some(day) = calendar.days.get(&date) {
+                    lines.push(Spans::from(day.description.clone()));
+                }
+
+                lines.push(Spans::from(match calendar.day_order(date) {
+                    Some(weekday) if weekday != date.weekday() => {
+                        format!("Classes follow the {:?} time table.", weekday)
+                    }
+                    Some(_) => "Regular classes.".to_string(),
+                    None => "No classes.".to_string(),
+                }));
+
+                lines
+            }
+            _ => vec![],
+        };
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, data)
+            .placeholder(
+                &[MONTHS_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No academic calendar for this semester."
+                },
+            )
+            .text(&[DAYS_BLOCK], month_lines)
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn calendar<'a>(block: &Block, data: &'a VtopData) -> Option<&'a AcademicCalendar> {
+    let semester = selected_semester(block, SEMESTER_BLOCK, data)?;
+    data.academic_calendar.get(&semester.id)
+}
+
+/// The chosen month, or the current month if it is in the calendar, or its first month.
+fn chosen_month(block: &Block, data: &VtopData) -> Option<NaiveDate> {
+    let months = &calendar(block, data)?.months;
+
+    if let Some(index) = block.get_inner_block(MONTHS_BLOCK)?.chosen_item() {
+        return months.get(index).copied();
+    }
+
+    let this_month = Local::now().date_naive().with_day(1)?;
+    if months.contains(&this_month) {
+        Some(this_month)
+    } else {
+        months.first().copied()
+    }
+}
+
+fn hovered_date(block: &Block, data: &VtopData) -> Option<NaiveDate> {
+    let index = block.get_inner_block(DAYS_BLOCK)?.hovered_item()?;
+    month_days(chosen_month(block, data)?).get(index).copied()
+}
+
+fn month_days(month: NaiveDate) -> Vec<NaiveDate> {
+    month
+        .iter_days()
+        .take_while(|date| date.month() == month.month())
+        .collect()
+}
+
+fn day_style(kind: Option<DayKind>) -> Style {
+    match kind {
+        Some(DayKind::Instructional) => Style::default().fg(Color::Green),
+        Some(DayKind::DayOrder(_)) => Style::default().fg(Color::Magenta),
+        Some(DayKind::Holiday) => Style::default().fg(Color::Red),
+        Some(DayKind::Exam) => Style::default().fg(Color::Yellow),
+        Some(DayKind::NoInstruction) => Style::default().fg(Color::DarkGray),
+        Some(DayKind::Other) => Style::default().fg(Color::LightBlue),
+        None => Style::default(),
+    }
+}
+
+/// Lays out the month as weeks from Monday, with a legend of the day colors.
+fn month_lines(
+    month: NaiveDate,
+    calendar: &AcademicCalendar,
+    hovered_date: Option<NaiveDate>,
+) -> Vec<Spans<'static>> {
+    let mut lines = vec![
+        Spans::from(Span::styled(
+            " Mo  Tu  We  Th  Fr  Sa  Su",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Spans::from(""),
+    ];
+
+    let mut week = vec![Span::raw(
+        "    ".repeat(month.weekday().num_days_from_monday() as usize),
+    )];
+
+    let days = month_days(month);
+    let last_day = days[days.len() - 1];
+
+    for date in days {
+        let mut style = day_style(calendar.days.get(&date).map(|day| day.kind));
+        if Some(date) == hovered_date {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        if date == Local::now().date_naive() {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+
+        week.push(Span::styled(format!(" {:>2}", date.day()), style));
+        week.push(Span::raw(" "));
+
+        if date.weekday() == Weekday::Sun || date == last_day {
+            lines.push(Spans::from(std::mem::take(&mut week)));
+            lines.push(Spans::from(""));
+        }
+    }
+
+    let legend = [
+        ("Instructional", DayKind::Instructional),
+        ("Day order", DayKind::DayOrder(Weekday::Mon)),
+        ("Holiday", DayKind::Holiday),
+        ("Exam", DayKind::Exam),
+        ("No instruction", DayKind::NoInstruction),
+    ];
+    lines.push(Spans::from(
+        legend
+            .iter()
+            .map(|(name, kind)| Span::styled(format!("{}  ", name), day_style(Some(*kind))))
+            .collect::<Vec<Span>>(),
+    ));
+
+    lines
+}
@@ -0,0 +1,81 @@
+use std::cell::Cell;
+
+use tui::layout::Rect;
+
+use super::{BlockEvent, ButtonAction};
+
+/// A button activated with Enter, a mouse click or its accelerator key, reporting its
+/// action to the page owning it.
+pub struct Button {
+    pub label: String,
+    /// Identifies what the button does to the page owning it.
+    pub action: ButtonAction,
+    /// A disabled button is grayed out and can't be activated.
+    pub enabled: bool,
+    /// Char activating the button while no inner block of its container is selected, or
+    /// with Alt from anywhere in the page.
+    pub accelerator: Option<char>,
+    /// Area the button was last drawn at, kept by the renderer for mouse clicks.
+    area: Cell<Rect>,
+}
+
+impl Button {
+    pub fn new(label: String) -> Button {
+        Button {
+            label,
+            action: ButtonAction::None,
+            enabled: true,
+            accelerator: None,
+            area: Cell::new(Rect::default()),
+        }
+    }
+
+    /// The label with the accelerator in brackets, like `[S]ubmit`. An accelerator
+    /// missing from the label is put before it.
+    pub fn display_label(&self) -> String {
+        let accelerator = match self.accelerator {
+            Some(accelerator) => accelerator,
+            None => return self.label.clone(),
+        };
+
+        match self
+            .label
+            .char_indices()
+            .find(|(_, ch)| ch.eq_ignore_ascii_case(&accelerator))
+        {
+            Some((index, ch)) => format!(
+                "{}[{}]{}",
+                &self.label[..index],
+                ch,
+                &self.label[index + ch.len_utf8()..]
+            ),
+            None => format!("[{}] {}", accelerator.to_ascii_uppercase(), self.label),
+        }
+    }
+
+    /// Whether the char is the accelerator of the button, ignoring case.
+    pub fn has_accelerator(&self, ch: char) -> bool {
+        self.accelerator
+            .is_some_and(|accelerator| accelerator.eq_ignore_ascii_case(&ch))
+    }
+
+    /// The event of activating the button, unless it is disabled.
+    pub fn press(&self) -> Option<BlockEvent> {
+        self.enabled
+            .then_some(BlockEvent::ButtonPressed(self.action))
+    }
+
+    pub fn set_area(&self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Whether the cell at the column and row is in the button as last drawn.
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        let area = self.area.get();
+
+        column >= area.x
+            && column < area.x + area.width
+            && row >= area.y
+            && row < area.y + area.height
+    }
+}
@@ -0,0 +1,249 @@
+use chrono::{Local, NaiveDate};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::DataTable,
+    input::Key,
+    models::{AttendanceEntry, VtopData},
+    network::NetworkEvent,
+    util::ATTENDANCE_THRESHOLD,
+};
+
+use super::{
+    export_hint, handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const COURSES_BLOCK: usize = 1;
+const DETAILS_BLOCK: usize = 2;
+
+pub struct ClassAttendancePage {}
+
+impl ClassAttendancePage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let courses = Block::default("Attendance".to_string(), BlockType::ListBlock);
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(courses).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(semester_page_layout(vec![
+            Constraint::Min(1),
+            Constraint::Length(9),
+        ]));
+    }
+
+    /// Also fetches the academic calendar used to forecast the attendance.
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        let semester_id = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        let mut events = vec![NetworkEvent::GetAttendance {
+            semester_id: semester_id.clone(),
+        }];
+        if semester_id
+            .as_ref()
+            .is_none_or(|id| !data.academic_calendar.contains_key(id))
+        {
+            events.push(NetworkEvent::GetAcademicCalendar { semester_id });
+        }
+
+        events
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        block.handle_input(key);
+        ClassAttendancePage::refresh(block, data);
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            return ClassAttendancePage::missing_data(block, data);
+        }
+
+        if block.selected_block() == Some(COURSES_BLOCK) && key == Key::Char('x') {
+            return vec![NetworkEvent::ExportTable {
+                table: DataTable::Attendance,
+                semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+            }];
+        }
+
+        vec![]
+    }
+
+    /// Fetches the attendance and academic calendar of the selected semester if they
+    /// aren't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        let semester_id = match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) => semester_id,
+            None => return vec![],
+        };
+
+        let mut events = vec![];
+        if !data.attendance.contains_key(&semester_id) {
+            events.push(NetworkEvent::GetAttendance {
+                semester_id: Some(semester_id.clone()),
+            });
+        }
+        if !data.academic_calendar.contains_key(&semester_id) {
+            events.push(NetworkEvent::GetAcademicCalendar {
+                semester_id: Some(semester_id),
+            });
+        }
+
+        events
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let items = attendance(block, data)
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{:>6.2}%{} {:<9} {:<40.40} {}/{}",
+                    entry.percentage,
+                    if entry.is_at_risk() { "!" } else { " " },
+                    entry.course_code,
+                    entry.course_title,
+                    entry.attended_classes,
+                    entry.total_classes
+                )
+            })
+            .collect();
+
+        if let Some(courses_block) = block.get_inner_block_mut(COURSES_BLOCK) {
+            courses_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let details = match hovered_entry(block_self, &app.state.data) {
+            Some(entry) => {
+                let mut lines = vec![
+                    Spans::from(vec![
+                        Span::styled("Course: ", label_style),
+                        Span::raw(format!(
+                            "{} - {} ({})",
+                            entry.course_code, entry.course_title, entry.course_type
+                        )),
+                    ]),
+                    Spans::from(vec![
+                        Span::styled("Faculty: ", label_style),
+                        Span::raw(format!("{} ({})", entry.faculty, entry.slot)),
+                    ]),
+                    Spans::from(vec![
+                        Span::styled("Attended: ", label_style),
+                        Span::styled(
+                            format!(
+                                "{} of {} classes ({:.2}%)",
+                                entry.attended_classes, entry.total_classes, entry.percentage
+                            ),
+                            if entry.is_at_risk() {
+                                Style::default().fg(Color::Red)
+                            } else {
+                                Style::default().fg(Color::Green)
+                            },
+                        ),
+                    ]),
+                ];
+
+                lines.push(
+                    match remaining_classes(block_self, &app.state.data, entry) {
+                        Some((remaining, last_day)) => {
+                            let spare = entry.classes_to_spare(remaining);
+                            Spans::from(format!(
+                                "{} classes left until {}. {}",
+                                remaining,
+                                last_day.format("%d-%b-%Y"),
+                                if spare >= 0 {
+                                    format!(
+                                        "You can miss {} of them and stay at {}%.",
+                                        spare, ATTENDANCE_THRESHOLD
+                                    )
+                                } else {
+                                    format!(
+                                        "Attending all of them gets you to {:.2}%.",
+                                        entry.best_percentage(remaining)
+                                    )
+                                }
+                            ))
+                        }
+                        None => Spans::from(format!(
+                            "Courses below {}% are marked with '!'.",
+                            ATTENDANCE_THRESHOLD
+                        )),
+                    },
+                );
+                lines.push(export_hint(app.config.export_format));
+
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, &app.state.data)
+            .placeholder(
+                &[COURSES_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No attendance posted this semester."
+                },
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn attendance<'a>(block: &Block, data: &'a VtopData) -> &'a [AttendanceEntry] {
+    selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.attendance.get(&semester.id))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Classes of the course left in the semester from today, and the last instructional
+/// day, if the academic calendar of the semester is fetched.
+fn remaining_classes(
+    block: &Block,
+    data: &VtopData,
+    entry: &AttendanceEntry,
+) -> Option<(u32, NaiveDate)> {
+    let semester = selected_semester(block, SEMESTER_BLOCK, data)?;
+    let calendar = data.academic_calendar.get(&semester.id)?;
+    let last_day = calendar.last_instructional_day()?;
+
+    let today = Local::now().date_naive();
+    Some((
+        calendar.class_count(&entry.timings(), today, last_day),
+        last_day,
+    ))
+}
+
+fn hovered_entry<'a>(block: &Block, data: &'a VtopData) -> Option<&'a AttendanceEntry> {
+    let index = block.get_inner_block(COURSES_BLOCK)?.hovered_item()?;
+    attendance(block, data).get(index)
+}
@@ -0,0 +1,129 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::{ClassMessage, VtopData},
+    network::NetworkEvent,
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType};
+
+const MESSAGES_BLOCK: usize = 0;
+const MESSAGE_BLOCK: usize = 1;
+
+pub struct ClassMessagesPage {}
+
+impl ClassMessagesPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let messages = Block::default("Class Messages".to_string(), BlockType::ListBlock);
+        let message = Block::default("Message".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(messages).unwrap();
+        block.append_inner_block(message).unwrap();
+
+        block.set_layout(
+            BlockLayout::vertical(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+                .margin(2),
+        );
+    }
+
+    /// Marks the hovered message as read, showing it from its start.
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_message = hovered_message(block, data).map(ClassMessage::key);
+
+        block.handle_input(key);
+
+        if block.selected_block() == Some(MESSAGES_BLOCK) {
+            if let Some(message) = hovered_message(block, data) {
+                data.read_messages.insert(message.key());
+            }
+        }
+
+        if hovered_message(block, data).map(ClassMessage::key) != previous_message {
+            if let Some(message_block) = block.get_inner_block_mut(MESSAGE_BLOCK) {
+                message_block.scroll_to_top();
+            }
+        }
+
+        ClassMessagesPage::refresh(block, data);
+        vec![]
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        let items = data
+            .class_messages
+            .iter()
+            .map(|message| {
+                format!(
+                    "{} {:<12} {:<30.30} {}",
+                    if data.read_messages.contains(&message.key()) {
+                        " "
+                    } else {
+                        "*"
+                    },
+                    message.posted_on,
+                    message.course,
+                    message.faculty
+                )
+            })
+            .collect();
+
+        if let Some(messages_block) = block.get_inner_block_mut(MESSAGES_BLOCK) {
+            messages_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let message = match hovered_message(block_self, &app.state.data) {
+            Some(message) => vec![
+                Spans::from(vec![
+                    Span::styled("Course: ", label_style),
+                    Span::raw(message.course.clone()),
+                ]),
+                Spans::from(vec![
+                    Span::styled("From: ", label_style),
+                    Span::raw(format!("{} on {}", message.faculty, message.posted_on)),
+                ]),
+                Spans::from(""),
+                Spans::from(message.message.clone()),
+            ],
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .placeholder(
+                &[MESSAGES_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No class messages."
+                },
+            )
+            .text(&[MESSAGE_BLOCK], message)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn hovered_message<'a>(block: &Block, data: &'a VtopData) -> Option<&'a ClassMessage> {
+    let index = block.get_inner_block(MESSAGES_BLOCK)?.hovered_item()?;
+    data.class_messages.get(index)
+}
@@ -0,0 +1,294 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::{Course, CourseMaterial, VtopData},
+    network::{DownloadRequest, NetworkEvent},
+};
+
+use super::{
+    handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockEvent, BlockLayout,
+    BlockRenderer, BlockType, ButtonAction,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const COURSES_BLOCK: usize = 1;
+const MATERIALS_BLOCK: usize = 2;
+const REFERENCES_BLOCK: usize = 3;
+const DOWNLOAD_BUTTON: usize = 4;
+
+pub struct CoursePage {}
+
+impl CoursePage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let courses = Block::default("Course".to_string(), BlockType::ListBlock);
+
+        let mut materials = Block::default("Course Materials".to_string(), BlockType::ListBlock);
+        materials.enable_multi_select();
+
+        let references =
+            Block::default("Reference Material".to_string(), BlockType::ParagraphBlock);
+        let mut download = Block::default("Download".to_string(), BlockType::Button);
+        download.set_button_action(ButtonAction::DownloadMaterials);
+        download.set_accelerator('d');
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(courses).unwrap();
+        block.append_inner_block(materials).unwrap();
+        block.append_inner_block(references).unwrap();
+        block.append_inner_block(download).unwrap();
+
+        block.set_layout(
+            semester_page_layout(vec![
+                Constraint::Length(10),
+                Constraint::Min(1),
+                Constraint::Length(3),
+            ])
+            .nest(
+                2,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(60),
+                    Constraint::Percentage(40),
+                ]),
+            ),
+        );
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetCoursePageCourses {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    /// Fetches the courses of the selected semester if they aren't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.course_page.courses.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetCoursePageCourses {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+        let previous_course = chosen_course(block, data).map(|course| course.class_id.clone());
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        let block_event = block.handle_input(key);
+
+        let semester_changed =
+            selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester;
+
+        // Lists depending on a changed choice are emptied so their old choices are dropped.
+        if semester_changed {
+            for index in [COURSES_BLOCK, MATERIALS_BLOCK] {
+                if let Some(list) = block.get_inner_block_mut(index) {
+                    list.set_list_items(vec![]);
+                }
+            }
+        } else if chosen_course(block, data).map(|course| &course.class_id)
+            != previous_course.as_ref()
+        {
+            if let Some(list) = block.get_inner_block_mut(MATERIALS_BLOCK) {
+                list.set_list_items(vec![]);
+            }
+        }
+
+        CoursePage::refresh(block, data);
+
+        let mut events = vec![];
+
+        if semester_changed {
+            events.extend(CoursePage::missing_data(block, data));
+        }
+
+        if let Some(course) = chosen_course(block, data) {
+            if previous_course.as_ref() != Some(&course.class_id)
+                && !data.course_page.materials.contains_key(&course.class_id)
+            {
+                events.push(NetworkEvent::GetCourseMaterials {
+                    class_id: course.class_id.clone(),
+                });
+            }
+        }
+
+        if let Some(block_event) = block_event {
+            events.extend(CoursePage::handle_event(block, block_event, data));
+        }
+
+        events
+    }
+
+    pub fn handle_event(
+        block: &mut Block,
+        event: BlockEvent,
+        data: &VtopData,
+    ) -> Vec<NetworkEvent> {
+        match event {
+            BlockEvent::ButtonPressed(ButtonAction::DownloadMaterials) => {
+                let events = download_event(block, data).into_iter().collect();
+                if let Some(materials) = block.get_inner_block_mut(MATERIALS_BLOCK) {
+                    materials.clear_marked_items();
+                }
+                CoursePage::refresh(block, data);
+
+                events
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let courses = selected_semester(block, SEMESTER_BLOCK, data)
+            .and_then(|semester| data.course_page.courses.get(&semester.id))
+            .map(|courses| courses.iter().map(Course::display_name).collect())
+            .unwrap_or_default();
+
+        if let Some(courses_block) = block.get_inner_block_mut(COURSES_BLOCK) {
+            courses_block.set_list_items(courses);
+        }
+
+        let materials: Vec<String> = course_materials(block, data)
+            .map(|materials| {
+                materials
+                    .iter()
+                    .map(|material| {
+                        format!(
+                            "{} | {} ({} files)",
+                            material.date,
+                            material.topic,
+                            material.links.len()
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let has_materials = !materials.is_empty();
+        if let Some(materials_block) = block.get_inner_block_mut(MATERIALS_BLOCK) {
+            materials_block.set_list_items(materials);
+        }
+
+        let marked_entries = block
+            .get_inner_block(MATERIALS_BLOCK)
+            .map(|materials| materials.marked_items().len())
+            .unwrap_or(0);
+
+        let download_label = match marked_entries {
+            0 => "Download the hovered entry (Space marks entries, 'a' marks all)".to_string(),
+            count => format!("Download {} marked entries", count),
+        };
+
+        if let Some(download_button) = block.get_inner_block_mut(DOWNLOAD_BUTTON) {
+            download_button.set_button_label(download_label);
+            download_button.set_enabled(has_materials);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+        let data = &app.state.data;
+
+        let loading_text = if app.is_loading {
+            "Loading..."
+        } else {
+            "Nothing to show."
+        };
+
+        let references = hovered_material(block_self, data)
+            .map(|material| {
+                let mut lines = vec![
+                    Spans::from(Span::styled(
+                        material.topic.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Spans::from(material.date.clone()),
+                    Spans::from(""),
+                ];
+                lines.extend(
+                    material
+                        .links
+                        .iter()
+                        .map(|link| Spans::from(format!("- {}", link.name))),
+                );
+                lines
+            })
+            .unwrap_or_default();
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, data)
+            .placeholder(&[COURSES_BLOCK], loading_text)
+            .placeholder(
+                &[MATERIALS_BLOCK],
+                if chosen_course(block_self, data).is_none() {
+                    "Choose a course."
+                } else {
+                    loading_text
+                },
+            )
+            .text(&[REFERENCES_BLOCK], references)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn chosen_course<'a>(block: &Block, data: &'a VtopData) -> Option<&'a Course> {
+    let semester = selected_semester(block, SEMESTER_BLOCK, data)?;
+    let index = block.get_inner_block(COURSES_BLOCK)?.chosen_item()?;
+    data.course_page.courses.get(&semester.id)?.get(index)
+}
+
+fn course_materials<'a>(block: &Block, data: &'a VtopData) -> Option<&'a Vec<CourseMaterial>> {
+    let course = chosen_course(block, data)?;
+    data.course_page.materials.get(&course.class_id)
+}
+
+fn hovered_material<'a>(block: &Block, data: &'a VtopData) -> Option<&'a CourseMaterial> {
+    let index = block.get_inner_block(MATERIALS_BLOCK)?.hovered_item()?;
+    course_materials(block, data)?.get(index)
+}
+
+/// Downloads the marked entries, or the hovered entry if no entry is marked.
+fn download_event(block: &Block, data: &VtopData) -> Option<NetworkEvent> {
+    let course = chosen_course(block, data)?;
+    let materials = course_materials(block, data)?;
+    let materials_block = block.get_inner_block(MATERIALS_BLOCK)?;
+
+    let mut indices = materials_block.marked_items();
+    if indices.is_empty() {
+        indices.push(materials_block.hovered_item()?);
+    }
+
+    let requests = indices
+        .into_iter()
+        .filter_map(|index| materials.get(index))
+        .flat_map(|material| {
+            let directory = material.relative_directory(&course.course_code);
+            material.links.iter().map(move |link| DownloadRequest {
+                url: link.url.clone(),
+                directory: directory.clone(),
+                file_name: link.name.clone(),
+            })
+        })
+        .collect();
+
+    Some(NetworkEvent::QueueDownloads { requests })
+}
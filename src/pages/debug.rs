@@ -1,15 +1,20 @@
+use regex::Regex;
 use tui::{
     backend::Backend,
-    layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
-    text::{Span, Spans},
-    widgets::Paragraph,
+    layout::{Constraint, Rect},
+    text::Spans,
     Frame,
 };
 
-use crate::app::App;
+use crate::{app::App, input::Key, network::NetworkEvent};
 
-use super::{Block, BlockContent, BlockType};
+use super::{Block, BlockEvent, BlockLayout, BlockRenderer, BlockType, ButtonAction, Validator};
+
+const FEEDBACK_FORM: usize = 1;
+const RESULT_BLOCK: usize = 2;
+
+/// Path of the paragraph block listing the notifications.
+const NOTIFICATIONS_PATH: [usize; 3] = [0, 0, 0];
 
 pub struct DebugPage {}
 
@@ -20,20 +25,85 @@ impl DebugPage {
         let mut temp4 = Block::default("Temp 4".to_string(), BlockType::ContainerBlock);
 
         let temp5 = Block::default("Temp 5".to_string(), BlockType::ParagraphBlock);
-        let temp6 = Block::default("Temp 6".to_string(), BlockType::ParagraphBlock);
-        let temp7 = Block::default("Temp 7".to_string(), BlockType::ParagraphBlock);
+        let mut temp6 = Block::default("Temp 6".to_string(), BlockType::ParagraphBlock);
+        temp6.set_paragraph_text("Hello, World!".to_string());
+        let mut temp7 = Block::default("Temp 7".to_string(), BlockType::ParagraphBlock);
+        temp7.set_paragraph_text("Tuition Framework".to_string());
 
         temp3.append_inner_block(temp5).unwrap();
         temp3.append_inner_block(temp6).unwrap();
         temp4.append_inner_block(temp7).unwrap();
 
+        let mut scratch_pad = Block::default("Scratch Pad".to_string(), BlockType::TextAreaBlock);
+        scratch_pad.set_placeholder("Type or paste anything here.".to_string());
+        scratch_pad.set_max_length(500);
+        temp4.append_inner_block(scratch_pad).unwrap();
+
+        temp3.set_layout(
+            BlockLayout::horizontal(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+                .margin(1)
+                .vertical_below(80),
+        );
+        temp4.set_layout(
+            BlockLayout::horizontal(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+                .margin(1)
+                .vertical_below(80),
+        );
+
         temp1.append_inner_block(temp3).unwrap();
         temp1.append_inner_block(temp4).unwrap();
 
+        temp1.set_layout(
+            BlockLayout::vertical(vec![Constraint::Min(1), Constraint::Length(10)]).margin(1),
+        );
+
         block.append_inner_block(temp1).unwrap();
+        block.append_inner_block(feedback_form()).unwrap();
 
-        let temp2 = Block::default("Temp Block".to_string(), BlockType::ParagraphBlock);
+        let mut temp2 = Block::default("Temp Block".to_string(), BlockType::ParagraphBlock);
+        temp2.set_paragraph_text("Test Block".to_string());
         block.append_inner_block(temp2).unwrap();
+
+        block.set_layout(
+            BlockLayout::vertical(vec![
+                Constraint::Min(1),
+                Constraint::Length(5),
+                Constraint::Length(3),
+            ])
+            .margin(2),
+        );
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key) -> Vec<NetworkEvent> {
+        match block.handle_input(key) {
+            Some(event) => DebugPage::handle_event(block, event),
+            None => vec![],
+        }
+    }
+
+    /// Shows the values of the feedback form in the block under it once it is submitted.
+    pub fn handle_event(block: &mut Block, event: BlockEvent) -> Vec<NetworkEvent> {
+        if event != BlockEvent::ButtonPressed(ButtonAction::SubmitForm) {
+            return vec![];
+        }
+
+        let values = block
+            .get_inner_block(FEEDBACK_FORM)
+            .map(Block::form_values)
+            .unwrap_or_default();
+        let value = |title: &str| values.get(title).cloned().unwrap_or_default();
+
+        let result = format!(
+            "Submitted a rating of {} from {} ({}).",
+            value("Rating"),
+            value("Name"),
+            value("Register Number")
+        );
+        if let Some(result_block) = block.get_inner_block_mut(RESULT_BLOCK) {
+            result_block.set_paragraph_text(result);
+        }
+
+        vec![]
     }
 
     pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
@@ -45,204 +115,76 @@ impl DebugPage {
             .page_block
             .block;
 
-        let layout = Layout::default()
-            .direction(tui::layout::Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(3)].as_ref())
-            .margin(2)
-            .split(layout_chunk);
-
-        let mut v = vec![];
-
-        for i in (0..app.state.notifications.len()).rev() {
-            v.push(Spans::from(app.state.notifications[i].text.clone()));
-        }
-
-        if let BlockContent::ContainerBlock {
-            inner_blocks,
-            selected_block,
-            hovered_block,
-        } = &block_self.content
-        {
-            let notifications_block = tui::widgets::Block::default()
-                .title(Span::styled(
-                    format!(" {} ", inner_blocks[0].title()),
-                    Style::default()
-                        .fg(Color::LightCyan)
-                        .add_modifier(Modifier::BOLD),
-                ))
-                .borders(inner_blocks[0].border())
-                .border_style(if let Some(0) = selected_block {
-                    Style::default().fg(Color::LightCyan)
-                } else if let Some(0) = hovered_block {
-                    Style::default().fg(Color::Magenta)
-                } else {
-                    Style::default()
-                });
-
-            f.render_widget(notifications_block, layout[0]);
-
-            let notifications_layout = Layout::default()
-                .direction(tui::layout::Direction::Vertical)
-                .constraints([Constraint::Min(1), Constraint::Length(7)].as_ref())
-                .margin(2)
-                .split(layout[0]);
-
-            if let BlockContent::ContainerBlock {
-                inner_blocks,
-                selected_block,
-                hovered_block,
-            } = &inner_blocks[0].content
-            {
-                let temp3block = tui::widgets::Block::default()
-                    .title(Span::styled(
-                        format!(" {} ", inner_blocks[0].title()),
-                        Style::default()
-                            .fg(Color::LightCyan)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .borders(inner_blocks[0].border())
-                    .border_style(if let Some(0) = selected_block {
-                        Style::default().fg(Color::LightCyan)
-                    } else if let Some(0) = hovered_block {
-                        Style::default().fg(Color::Magenta)
-                    } else {
-                        Style::default()
-                    });
-
-                f.render_widget(temp3block, notifications_layout[0]);
-
-                let temp4block = tui::widgets::Block::default()
-                    .title(Span::styled(
-                        format!(" {} ", inner_blocks[1].title()),
-                        Style::default()
-                            .fg(Color::LightCyan)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .borders(inner_blocks[1].border())
-                    .border_style(if let Some(1) = selected_block {
-                        Style::default().fg(Color::LightCyan)
-                    } else if let Some(1) = hovered_block {
-                        Style::default().fg(Color::Magenta)
-                    } else {
-                        Style::default()
-                    });
-
-                f.render_widget(temp4block, notifications_layout[1]);
-
-                let temp3_layout = Layout::default()
-                    .direction(tui::layout::Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
-                    .margin(2)
-                    .split(notifications_layout[0]);
-
-                let temp4_layout = Layout::default()
-                    .direction(tui::layout::Direction::Vertical)
-                    .constraints([Constraint::Length(10)].as_ref())
-                    .margin(2)
-                    .split(notifications_layout[1]);
-
-                if let BlockContent::ContainerBlock {
-                    inner_blocks,
-                    selected_block,
-                    hovered_block,
-                } = &inner_blocks[0].content
-                {
-                    let temp5 = Paragraph::new(v)
-                        .alignment(tui::layout::Alignment::Center)
-                        .block(
-                            tui::widgets::Block::default()
-                                .title(Span::styled(
-                                    format!(" {} ", inner_blocks[0].title()),
-                                    Style::default()
-                                        .fg(Color::LightCyan)
-                                        .add_modifier(Modifier::BOLD),
-                                ))
-                                .borders(inner_blocks[0].border())
-                                .border_style(if let Some(0) = selected_block {
-                                    Style::default().fg(Color::LightCyan)
-                                } else if let Some(0) = hovered_block {
-                                    Style::default().fg(Color::Magenta)
-                                } else {
-                                    Style::default()
-                                }),
-                        );
-
-                    f.render_widget(temp5, temp3_layout[0]);
-
-                    let temp6 = Paragraph::new("Hello, World!")
-                        .alignment(tui::layout::Alignment::Center)
-                        .block(
-                            tui::widgets::Block::default()
-                                .title(Span::styled(
-                                    format!(" {} ", inner_blocks[1].title()),
-                                    Style::default()
-                                        .fg(Color::LightCyan)
-                                        .add_modifier(Modifier::BOLD),
-                                ))
-                                .borders(inner_blocks[1].border())
-                                .border_style(if let Some(1) = selected_block {
-                                    Style::default().fg(Color::LightCyan)
-                                } else if let Some(1) = hovered_block {
-                                    Style::default().fg(Color::Magenta)
-                                } else {
-                                    Style::default()
-                                }),
-                        );
-
-                    f.render_widget(temp6, temp3_layout[1]);
-                }
-
-                if let BlockContent::ContainerBlock {
-                    inner_blocks,
-                    selected_block,
-                    hovered_block,
-                } = &inner_blocks[1].content
-                {
-                    let temp7 = Paragraph::new("Tuition Framework")
-                        .alignment(tui::layout::Alignment::Center)
-                        .block(
-                            tui::widgets::Block::default()
-                                .title(Span::styled(
-                                    format!(" {} ", inner_blocks[0].title()),
-                                    Style::default()
-                                        .fg(Color::LightCyan)
-                                        .add_modifier(Modifier::BOLD),
-                                ))
-                                .borders(inner_blocks[0].border())
-                                .border_style(if let Some(0) = selected_block {
-                                    Style::default().fg(Color::LightCyan)
-                                } else if let Some(0) = hovered_block {
-                                    Style::default().fg(Color::Magenta)
-                                } else {
-                                    Style::default()
-                                }),
-                        );
-
-                    f.render_widget(temp7, temp4_layout[0]);
-                }
-            }
-
-            let temp_block = Paragraph::new("Test Block")
-                .alignment(tui::layout::Alignment::Center)
-                .block(
-                    tui::widgets::Block::default()
-                        .title(Span::styled(
-                            format!(" {} ", inner_blocks[1].title()),
-                            Style::default()
-                                .fg(Color::LightCyan)
-                                .add_modifier(Modifier::BOLD),
-                        ))
-                        .borders(inner_blocks[1].border())
-                        .border_style(if let Some(1) = selected_block {
-                            Style::default().fg(Color::LightCyan)
-                        } else if let Some(1) = hovered_block {
-                            Style::default().fg(Color::Magenta)
-                        } else {
-                            Style::default()
-                        }),
-                );
-
-            f.render_widget(temp_block, layout[1]);
-        }
+        let notifications = app
+            .state
+            .notifications
+            .iter()
+            .rev()
+            .map(|notification| Spans::from(notification.text.clone()))
+            .collect();
+
+        BlockRenderer::default()
+            .text(&NOTIFICATIONS_PATH, notifications)
+            .draw(f, block_self, layout_chunk);
     }
 }
+
+/// A form trying out the validators, with its fields side by side.
+fn feedback_form() -> Block {
+    let mut form = Block::default("Feedback".to_string(), BlockType::FormBlock);
+
+    let mut register_number = Block::default("Register Number".to_string(), BlockType::InputBlock);
+    register_number.set_placeholder("21BCE1234".to_string());
+    form.append_form_field(
+        register_number,
+        vec![Validator::Required, Validator::RegisterNumber],
+    )
+    .unwrap();
+
+    let name = Block::default("Name".to_string(), BlockType::InputBlock);
+    form.append_form_field(
+        name,
+        vec![
+            Validator::Required,
+            Validator::Length {
+                min: Some(3),
+                max: Some(40),
+            },
+        ],
+    )
+    .unwrap();
+
+    let mut email = Block::default("Email".to_string(), BlockType::InputBlock);
+    email.set_placeholder("Optional".to_string());
+    form.append_form_field(
+        email,
+        vec![Validator::Pattern {
+            pattern: Regex::new(r"^[^@\s]+@[^@\s]+\.[A-Za-z]{2,}$").unwrap(),
+            message: "Enter an email like name@vitstudent.ac.in.".to_string(),
+        }],
+    )
+    .unwrap();
+
+    let mut rating = Block::default("Rating".to_string(), BlockType::InputBlock);
+    rating.set_placeholder("1 to 5".to_string());
+    form.append_form_field(
+        rating,
+        vec![Validator::Required, Validator::Range { min: 1.0, max: 5.0 }],
+    )
+    .unwrap();
+
+    let mut submit = Block::default("Submit".to_string(), BlockType::Button);
+    submit.set_button_action(ButtonAction::SubmitForm);
+    submit.set_accelerator('s');
+    form.append_form_submit(submit).unwrap();
+
+    form.set_layout(BlockLayout::horizontal(vec![
+        Constraint::Percentage(22),
+        Constraint::Percentage(22),
+        Constraint::Percentage(22),
+        Constraint::Percentage(18),
+        Constraint::Min(12),
+    ]));
+
+    form
+}
@@ -0,0 +1,142 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::VtopData,
+    network::{Download, DownloadStatus, NetworkEvent},
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType};
+
+const DOWNLOADS_BLOCK: usize = 0;
+const DETAILS_BLOCK: usize = 1;
+
+pub struct DownloadsPage {}
+
+impl DownloadsPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let downloads = Block::default("Downloads".to_string(), BlockType::ListBlock);
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(downloads).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(
+            BlockLayout::vertical(vec![Constraint::Min(1), Constraint::Length(8)]).margin(2),
+        );
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &VtopData) -> Vec<NetworkEvent> {
+        block.handle_input(key);
+
+        if block.selected_block() != Some(DOWNLOADS_BLOCK) {
+            return vec![];
+        }
+
+        let download = match hovered_download(block, data) {
+            Some(download) => download,
+            None => return vec![],
+        };
+
+        match key {
+            Key::Char('r') => vec![NetworkEvent::RetryDownload { id: download.id }],
+            Key::Char('c') => vec![NetworkEvent::CancelDownload { id: download.id }],
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        let items = data
+            .downloads
+            .iter()
+            .map(|download| {
+                format!(
+                    "{:<40.40} {:<12} {:<18} {}",
+                    download.name(),
+                    download.status_text(),
+                    download.progress_text(),
+                    download.speed_text()
+                )
+            })
+            .collect();
+
+        if let Some(downloads_block) = block.get_inner_block_mut(DOWNLOADS_BLOCK) {
+            downloads_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let details = match hovered_download(block_self, &app.state.data) {
+            Some(download) => {
+                let mut lines = vec![
+                    Spans::from(vec![
+                        Span::styled("Url: ", label_style),
+                        Span::raw(download.request.url.clone()),
+                    ]),
+                    Spans::from(vec![
+                        Span::styled("Saved to: ", label_style),
+                        Span::raw(
+                            download
+                                .path
+                                .as_ref()
+                                .unwrap_or(&download.directory)
+                                .display()
+                                .to_string(),
+                        ),
+                    ]),
+                ];
+
+                match &download.status {
+                    DownloadStatus::Failed(error) => lines.push(Spans::from(vec![
+                        Span::styled("Error: ", label_style),
+                        Span::styled(error.clone(), Style::default().fg(Color::Red)),
+                    ])),
+                    DownloadStatus::Duplicate(existing_path) => lines.push(Spans::from(vec![
+                        Span::styled("Same content as: ", label_style),
+                        Span::raw(existing_path.display().to_string()),
+                    ])),
+                    _ => (),
+                }
+
+                lines.push(Spans::from(""));
+                lines.push(Spans::from(
+                    "'r' retries and 'c' cancels the hovered download.",
+                ));
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .placeholder(
+                &[DOWNLOADS_BLOCK],
+                "Nothing downloaded yet. Downloads queued from the other pages show up here.",
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn hovered_download<'a>(block: &Block, data: &'a VtopData) -> Option<&'a Download> {
+    let index = block.get_inner_block(DOWNLOADS_BLOCK)?.hovered_item()?;
+    data.downloads.get(index)
+}
@@ -0,0 +1,228 @@
+use chrono::{Local, NaiveDate};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::DataTable,
+    input::Key,
+    models::{same_day_exams, ExamScheduleEntry, VtopData},
+    network::NetworkEvent,
+};
+
+use super::{
+    handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const EXAMS_BLOCK: usize = 1;
+const DETAILS_BLOCK: usize = 2;
+
+pub struct ExamSchedulePage {}
+
+impl ExamSchedulePage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let exams = Block::default("Exams".to_string(), BlockType::ListBlock);
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(exams).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(semester_page_layout(vec![
+            Constraint::Min(1),
+            Constraint::Length(9),
+        ]));
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetExamSchedule {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        block.handle_input(key);
+        ExamSchedulePage::refresh(block, data);
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            return ExamSchedulePage::missing_data(block, data);
+        }
+
+        if block.selected_block() == Some(EXAMS_BLOCK) && key == Key::Char('x') {
+            return vec![NetworkEvent::ExportTable {
+                table: DataTable::ExamSchedule,
+                semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+            }];
+        }
+
+        if block.selected_block() == Some(EXAMS_BLOCK) && key == Key::Char('c') {
+            return vec![NetworkEvent::SyncCalendar {
+                semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+            }];
+        }
+
+        vec![]
+    }
+
+    /// Fetches the exam schedule of the selected semester if it isn't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.exam_schedule.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetExamSchedule {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let today = Local::now().date_naive();
+        let exams = exams(block, data);
+        let clash_dates = clash_dates(block, data, today);
+
+        let items = exams
+            .iter()
+            .map(|exam| {
+                let is_clashing = exam.date.is_some_and(|date| clash_dates.contains(&date));
+
+                format!(
+                    "{} {:<36} {:<12} {:<3} {}",
+                    if is_clashing { "!" } else { " " },
+                    exam.countdown(today),
+                    exam.date
+                        .map(|date| date.format("%d-%b-%Y").to_string())
+                        .unwrap_or_default(),
+                    exam.session,
+                    exam.venue
+                )
+            })
+            .collect();
+
+        if let Some(exams_block) = block.get_inner_block_mut(EXAMS_BLOCK) {
+            exams_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+        let data = &app.state.data;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+        let field = |label: &str, value: String| {
+            Spans::from(vec![
+                Span::styled(format!("{}: ", label), label_style),
+                Span::raw(value),
+            ])
+        };
+
+        let details = match hovered_exam(block_self, data) {
+            Some(exam) => {
+                let mut lines = vec![
+                    field(
+                        "Course",
+                        format!(
+                            "{} - {} ({})",
+                            exam.course_code, exam.course_title, exam.slot
+                        ),
+                    ),
+                    field(
+                        "Exam",
+                        format!(
+                            "{} on {} {}",
+                            exam.exam_type,
+                            exam.date
+                                .map(|date| date.format("%A, %d %B %Y").to_string())
+                                .unwrap_or_else(|| "a date not announced yet".to_string()),
+                            exam.session
+                        ),
+                    ),
+                    field(
+                        "Timing",
+                        format!("{} (report by {})", exam.exam_time, exam.reporting_time),
+                    ),
+                    field("Venue", exam.venue.clone()),
+                    field(
+                        "Seat",
+                        format!("{} at {}", exam.seat_number, exam.seat_location),
+                    ),
+                ];
+
+                let today = Local::now().date_naive();
+                if exam
+                    .date
+                    .is_some_and(|date| clash_dates(block_self, data, today).contains(&date))
+                {
+                    lines.push(Spans::from(Span::styled(
+                        "Another exam is on the same day.",
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+
+                lines.push(Spans::from(Span::styled(
+                    "'x' exports the table, 'c' syncs the exams and the time table to CalDAV.",
+                    Style::default().fg(Color::DarkGray),
+                )));
+
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, data)
+            .placeholder(
+                &[EXAMS_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No exams scheduled for this semester."
+                },
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+/// Exams of the selected semester by date, the exams without a date last.
+fn exams<'a>(block: &Block, data: &'a VtopData) -> Vec<&'a ExamScheduleEntry> {
+    let mut exams = selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.exam_schedule.get(&semester.id))
+        .map(|exams| exams.iter().collect::<Vec<&ExamScheduleEntry>>())
+        .unwrap_or_default();
+
+    exams.sort_by_key(|exam| (exam.date.is_none(), exam.date, exam.session.clone()));
+    exams
+}
+
+fn clash_dates(block: &Block, data: &VtopData, today: NaiveDate) -> Vec<NaiveDate> {
+    selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.exam_schedule.get(&semester.id))
+        .map(|exams| same_day_exams(exams, today).into_keys().collect())
+        .unwrap_or_default()
+}
+
+fn hovered_exam<'a>(block: &Block, data: &'a VtopData) -> Option<&'a ExamScheduleEntry> {
+    let index = block.get_inner_block(EXAMS_BLOCK)?.hovered_item()?;
+    exams(block, data).get(index).copied()
+}
@@ -0,0 +1,286 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex::Regex;
+
+use super::{Block, BlockContent, BlockEvent, ButtonAction};
+
+/// Format of VIT register numbers, like 21BCE1234.
+static REGISTER_NUMBER_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{2}[A-Za-z]{3}\d{4}$").unwrap());
+
+/// A check of the value of a form field, failing with a message shown under the field.
+#[derive(Clone)]
+pub enum Validator {
+    /// The value has more than whitespace.
+    Required,
+    /// The value matches the pattern, or else the message is shown. The pattern is
+    /// searched for in the value, so it is anchored with `^` and `$` to match the whole
+    /// value.
+    Pattern { pattern: Regex, message: String },
+    /// The value has at least `min` and at most `max` chars.
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// The value is a number between `min` and `max`, both included.
+    Range { min: f64, max: f64 },
+    /// The value is a VIT register number.
+    RegisterNumber,
+}
+
+impl Validator {
+    /// Checks the value. Values left empty are only checked by `Required`, so that
+    /// optional fields can be left out.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return match self {
+                Validator::Required => Err("This field is required.".to_string()),
+                _ => Ok(()),
+            };
+        }
+
+        match self {
+            Validator::Required => Ok(()),
+            Validator::Pattern { pattern, message } => match pattern.is_match(value) {
+                true => Ok(()),
+                false => Err(message.clone()),
+            },
+            Validator::Length { min, max } => {
+                let length = value.chars().count();
+                match (min, max) {
+                    (Some(min), _) if length < *min => {
+                        Err(format!("Use at least {} characters.", min))
+                    }
+                    (_, Some(max)) if length > *max => {
+                        Err(format!("Use at most {} characters.", max))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Validator::Range { min, max } => match value.parse::<f64>() {
+                Ok(number) if (*min..=*max).contains(&number) => Ok(()),
+                Ok(_) => Err(format!("Enter a number from {} to {}.", min, max)),
+                Err(_) => Err("Enter a number.".to_string()),
+            },
+            Validator::RegisterNumber => match REGISTER_NUMBER_PATTERN.is_match(value) {
+                true => Ok(()),
+                false => Err("Enter a register number like 21BCE1234.".to_string()),
+            },
+        }
+    }
+}
+
+/// Fields and submit button of a form block, a container block whose input and text
+/// area blocks are validated.
+///
+/// A field is validated when it is left with Esc and every field is validated when the
+/// form is submitted. The submit button is only enabled while every field is valid, but
+/// the error of a field is only shown once it has been validated, so that a new form
+/// isn't covered in errors.
+#[derive(Default)]
+pub struct Form {
+    fields: Vec<Field>,
+    submit: Option<Submit>,
+}
+
+struct Field {
+    /// Index of the field's block in the form.
+    index: usize,
+    validators: Vec<Validator>,
+    error: Option<String>,
+}
+
+struct Submit {
+    index: usize,
+    action: ButtonAction,
+}
+
+impl Form {
+    pub fn add_field(&mut self, index: usize, validators: Vec<Validator>) {
+        self.fields.push(Field {
+            index,
+            validators,
+            error: None,
+        });
+    }
+
+    pub fn set_submit(&mut self, index: usize, action: ButtonAction) {
+        self.submit = Some(Submit { index, action });
+    }
+
+    /// Error shown under the block at the index, if it is an invalid field.
+    pub fn error(&self, index: usize) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.index == index)
+            .and_then(|field| field.error.as_deref())
+    }
+
+    /// Values of the fields, by the titles of their blocks.
+    pub fn values(&self, blocks: &[Block]) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let block = blocks.get(field.index)?;
+                Some((block.title(), field_value(block)))
+            })
+            .collect()
+    }
+
+    /// Validates the field at the index and shows its error, as when it is left.
+    pub fn validate_field(&mut self, blocks: &[Block], index: usize) {
+        if let Some(field) = self.fields.iter_mut().find(|field| field.index == index) {
+            field.error = field_error(field, blocks).err();
+        }
+    }
+
+    /// Validates every field and shows their errors, returning whether they are valid.
+    pub fn validate(&mut self, blocks: &[Block]) -> bool {
+        for field in self.fields.iter_mut() {
+            field.error = field_error(field, blocks).err();
+        }
+
+        self.fields.iter().all(|field| field.error.is_none())
+    }
+
+    /// Passes the event of a block of the form on, unless it is a submit of invalid
+    /// fields.
+    pub fn handle_event(&mut self, blocks: &[Block], event: BlockEvent) -> Option<BlockEvent> {
+        match (&self.submit, event) {
+            (Some(submit), BlockEvent::ButtonPressed(action)) if action == submit.action => {
+                self.validate(blocks).then_some(event)
+            }
+            _ => Some(event),
+        }
+    }
+
+    /// Enables the submit button while every field is valid, without showing errors.
+    pub fn update_submit(&self, blocks: &mut [Block]) {
+        let is_valid = self
+            .fields
+            .iter()
+            .all(|field| field_error(field, blocks).is_ok());
+
+        if let Some(button) = self
+            .submit
+            .as_ref()
+            .and_then(|submit| blocks.get_mut(submit.index))
+        {
+            button.set_enabled(is_valid);
+        }
+    }
+}
+
+fn field_value(block: &Block) -> String {
+    match &block.content {
+        BlockContent::InputBlock { input } => input.text().to_string(),
+        BlockContent::TextAreaBlock { text_area } => text_area.text().to_string(),
+        _ => String::new(),
+    }
+}
+
+fn field_error(field: &Field, blocks: &[Block]) -> Result<(), String> {
+    let value = blocks.get(field.index).map(field_value).unwrap_or_default();
+
+    field
+        .validators
+        .iter()
+        .try_for_each(|validator| validator.validate(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(pattern: &str) -> Validator {
+        Validator::Pattern {
+            pattern: Regex::new(pattern).unwrap(),
+            message: "Invalid.".to_string(),
+        }
+    }
+
+    #[test]
+    fn requires_more_than_whitespace() {
+        assert_eq!(
+            Validator::Required.validate("  "),
+            Err("This field is required.".to_string())
+        );
+        assert_eq!(Validator::Required.validate(" a "), Ok(()));
+    }
+
+    #[test]
+    fn leaves_empty_optional_fields_alone() {
+        let validators = [
+            pattern("^a$"),
+            Validator::Length {
+                min: Some(3),
+                max: None,
+            },
+            Validator::Range { min: 1.0, max: 5.0 },
+            Validator::RegisterNumber,
+        ];
+
+        for validator in validators {
+            assert_eq!(validator.validate(" "), Ok(()));
+        }
+    }
+
+    #[test]
+    fn matches_anchored_patterns_against_the_whole_value() {
+        let email = pattern(r"^[^@\s]+@[^@\s]+\.[A-Za-z]{2,}$");
+        assert_eq!(email.validate("name@vitstudent.ac.in"), Ok(()));
+        assert_eq!(email.validate(" name@vit.ac.in "), Ok(()));
+        assert_eq!(email.validate("name@vit"), Err("Invalid.".to_string()));
+        assert_eq!(
+            email.validate("a name@vit.ac.in"),
+            Err("Invalid.".to_string())
+        );
+
+        // Without anchors, the pattern is found anywhere in the value.
+        assert_eq!(pattern(r"\d+").validate("room 101"), Ok(()));
+    }
+
+    #[test]
+    fn counts_length_in_chars() {
+        let length = Validator::Length {
+            min: Some(2),
+            max: Some(4),
+        };
+
+        assert_eq!(
+            length.validate("a"),
+            Err("Use at least 2 characters.".to_string())
+        );
+        assert_eq!(
+            length.validate("héllo"),
+            Err("Use at most 4 characters.".to_string())
+        );
+        assert_eq!(length.validate("ünï"), Ok(()));
+    }
+
+    #[test]
+    fn checks_numbers_are_in_range() {
+        let range = Validator::Range { min: 1.0, max: 5.0 };
+
+        assert_eq!(range.validate("1"), Ok(()));
+        assert_eq!(range.validate("4.5"), Ok(()));
+        assert_eq!(range.validate("5"), Ok(()));
+        assert_eq!(
+            range.validate("6"),
+            Err("Enter a number from 1 to 5.".to_string())
+        );
+        assert_eq!(range.validate("five"), Err("Enter a number.".to_string()));
+    }
+
+    #[test]
+    fn checks_register_numbers() {
+        let error = Err("Enter a register number like 21BCE1234.".to_string());
+
+        assert_eq!(Validator::RegisterNumber.validate("21BCE1234"), Ok(()));
+        assert_eq!(Validator::RegisterNumber.validate("21bce1234"), Ok(()));
+        assert_eq!(Validator::RegisterNumber.validate("21BCE123"), error);
+        assert_eq!(Validator::RegisterNumber.validate("21BCE12345"), error);
+        assert_eq!(Validator::RegisterNumber.validate("x21BCE1234"), error);
+    }
+}
@@ -0,0 +1,207 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::DataTable,
+    input::Key,
+    models::{CourseGrade, VtopData},
+    network::NetworkEvent,
+};
+
+use super::{
+    export_hint, handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const GRADES_BLOCK: usize = 1;
+const DETAILS_BLOCK: usize = 2;
+
+pub struct GradesPage {}
+
+impl GradesPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let mut grades = Block::default("Grades".to_string(), BlockType::TableBlock);
+        grades.set_table_header(
+            ["Grade", "Code", "Course", "Type", "Credits", "Total"]
+                .map(String::from)
+                .to_vec(),
+        );
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(grades).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(semester_page_layout(vec![
+            Constraint::Min(1),
+            Constraint::Length(8),
+        ]));
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetGrades {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        block.handle_input(key);
+        GradesPage::refresh(block, data);
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            return GradesPage::missing_data(block, data);
+        }
+
+        if block.selected_block() == Some(GRADES_BLOCK) && key == Key::Char('x') {
+            return vec![NetworkEvent::ExportTable {
+                table: DataTable::Grades,
+                semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+            }];
+        }
+
+        vec![]
+    }
+
+    /// Fetches the grades of the selected semester if they aren't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.grades.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetGrades {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let rows = grades(block, data)
+            .iter()
+            .map(|grade| {
+                vec![
+                    grade.grade.clone(),
+                    grade.course_code.clone(),
+                    grade.course_title.clone(),
+                    grade.course_type.clone(),
+                    grade.credits.to_string(),
+                    grade
+                        .grand_total
+                        .map(|total| total.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+
+        if let Some(grades_block) = block.get_inner_block_mut(GRADES_BLOCK) {
+            grades_block.set_table_rows(rows);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+        let data = &app.state.data;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let mut details = match hovered_grade(block_self, data) {
+            Some(grade) => vec![
+                Spans::from(vec![
+                    Span::styled("Course: ", label_style),
+                    Span::raw(format!(
+                        "{} - {} ({})",
+                        grade.course_code, grade.course_title, grade.course_type
+                    )),
+                ]),
+                Spans::from(vec![
+                    Span::styled("Grading: ", label_style),
+                    Span::raw(grade.grading_type.clone()),
+                ]),
+                Spans::from(vec![
+                    Span::styled("Grand total: ", label_style),
+                    Span::raw(
+                        grade
+                            .grand_total
+                            .map(|total| total.to_string())
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                ]),
+            ],
+            None => vec![],
+        };
+
+        if let Some(gpa) = semester_gpa(grades(block_self, data)) {
+            details.push(Spans::from(vec![
+                Span::styled("Semester GPA: ", label_style),
+                Span::raw(format!("{:.2}", gpa)),
+            ]));
+        }
+        details.push(export_hint(app.config.export_format));
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, data)
+            .placeholder(
+                &[GRADES_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No grades published for this semester."
+                },
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn grades<'a>(block: &Block, data: &'a VtopData) -> &'a [CourseGrade] {
+    selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.grades.get(&semester.id))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn hovered_grade<'a>(block: &Block, data: &'a VtopData) -> Option<&'a CourseGrade> {
+    let index = block.get_inner_block(GRADES_BLOCK)?.selected_row()?;
+    grades(block, data).get(index)
+}
+
+/// Credit weighted average of the grade points, skipping courses without grade points.
+fn semester_gpa(grades: &[CourseGrade]) -> Option<f64> {
+    let (points, credits) = grades
+        .iter()
+        .filter_map(|grade| Some((grade.grade_points()?, grade.credits)))
+        .fold(
+            (0.0, 0.0),
+            |(points, credits), (grade_points, grade_credits)| {
+                (
+                    points + grade_points * grade_credits,
+                    credits + grade_credits,
+                )
+            },
+        );
+
+    if credits > 0.0 {
+        Some(points / credits)
+    } else {
+        None
+    }
+}
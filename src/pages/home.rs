@@ -0,0 +1,380 @@
+use chrono::{Datelike, Duration, Local, NaiveTime};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::{TimetableEntry, VtopData},
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType, Page, PageAction};
+
+const TODAY_BLOCK: usize = 0;
+const ATTENDANCE_BLOCK: usize = 1;
+const MARKS_BLOCK: usize = 2;
+const MESSAGES_BLOCK: usize = 3;
+const SPOTLIGHT_BLOCK: usize = 4;
+const WHATS_NEW_BLOCK: usize = 5;
+
+/// Number of items listed on the spotlight card.
+const SPOTLIGHT_ITEMS: usize = 5;
+/// Number of changes listed on the what's new card.
+const WHATS_NEW_ITEMS: usize = 5;
+
+pub struct HomePage {}
+
+impl HomePage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let cards = [
+            "Today's Classes",
+            "Attendance at Risk",
+            "Latest Marks",
+            "Unread Messages",
+            "Spotlight",
+            "What's New",
+        ];
+
+        for title in cards {
+            let card = Block::default(title.to_string(), BlockType::ParagraphBlock);
+            block.append_inner_block(card).unwrap();
+        }
+
+        block.set_layout(
+            BlockLayout::vertical(vec![
+                Constraint::Percentage(40),
+                Constraint::Percentage(35),
+                Constraint::Percentage(25),
+            ])
+            .margin(2)
+            .nest(
+                0,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(55),
+                    Constraint::Percentage(45),
+                ]),
+            )
+            .nest(
+                1,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ]),
+            )
+            .nest(
+                2,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ]),
+            ),
+        );
+    }
+
+    /// Cards have nothing to select, so selecting a card opens its full page instead. The
+    /// what's new card has no page of its own.
+    pub fn handle_input(block: &mut Block, key: Key, _data: &VtopData) -> Vec<PageAction> {
+        block.handle_input(key);
+
+        let page = match block.selected_block() {
+            Some(TODAY_BLOCK) => Page::TimeTable,
+            Some(ATTENDANCE_BLOCK) => Page::ClassAttendance,
+            Some(MARKS_BLOCK) => Page::Marks,
+            Some(MESSAGES_BLOCK) => Page::ClassMessages,
+            Some(SPOTLIGHT_BLOCK) => Page::Spotlight,
+            Some(WHATS_NEW_BLOCK) => {
+                block.unselect_inner_block();
+                return vec![];
+            }
+            _ => return vec![],
+        };
+
+        block.unselect_inner_block();
+        vec![PageAction::OpenPage(page)]
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+        let data = &app.state.data;
+
+        let placeholder = |text: &'static str| {
+            vec![Spans::from(Span::styled(
+                if app.is_loading { "Loading..." } else { text },
+                Style::default().fg(Color::DarkGray),
+            ))]
+        };
+
+        let today = today_lines(data);
+        let today = if today.is_empty() {
+            placeholder("No more classes today.")
+        } else {
+            today
+        };
+
+        let attendance = attendance_lines(data);
+        let attendance = if attendance.is_empty() {
+            placeholder("No course is below the attendance threshold.")
+        } else {
+            attendance
+        };
+
+        let marks = marks_lines(data);
+        let marks = if marks.is_empty() {
+            placeholder("No marks posted yet.")
+        } else {
+            marks
+        };
+
+        let messages = messages_lines(data);
+        let messages = if messages.is_empty() {
+            placeholder("No unread class messages.")
+        } else {
+            messages
+        };
+
+        let spotlight = spotlight_lines(data);
+        let spotlight = if spotlight.is_empty() {
+            placeholder("Nothing in the spotlight.")
+        } else {
+            spotlight
+        };
+
+        let whats_new = whats_new_lines(data);
+        let whats_new = if whats_new.is_empty() {
+            placeholder("Nothing changed since the last fetch.")
+        } else {
+            whats_new
+        };
+
+        BlockRenderer::default()
+            .text(&[TODAY_BLOCK], today)
+            .text(&[ATTENDANCE_BLOCK], attendance)
+            .text(&[MARKS_BLOCK], marks)
+            .text(&[MESSAGES_BLOCK], messages)
+            .text(&[SPOTLIGHT_BLOCK], spotlight)
+            .text(&[WHATS_NEW_BLOCK], whats_new)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn label_style() -> Style {
+    Style::default()
+        .fg(Color::LightCyan)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Formats a duration like `1h 05m` or `12m`.
+fn format_countdown(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(1);
+
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Classes of the global semester which haven't ended yet today, with a countdown to
+/// the next one. Holidays and day orders of the academic calendar are followed once it
+/// is fetched.
+fn today_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    let semester = match data.global_semester() {
+        Some(semester) => semester,
+        None => return vec![],
+    };
+    let timetable = match data.timetable.get(&semester.id) {
+        Some(timetable) => timetable,
+        None => return vec![],
+    };
+
+    let now = Local::now().naive_local();
+    let time = now.time();
+
+    let calendar = data.academic_calendar.get(&semester.id);
+    let weekday = match calendar.map(|calendar| calendar.day_order(now.date())) {
+        Some(Some(weekday)) => weekday,
+        Some(None) => {
+            let description = calendar
+                .and_then(|calendar| calendar.days.get(&now.date()))
+                .map(|day| day.description.clone())
+                .unwrap_or_default();
+
+            return vec![Spans::from(vec![
+                Span::styled("No classes today. ", label_style()),
+                Span::raw(description),
+            ])];
+        }
+        None => now.weekday(),
+    };
+
+    let mut classes: Vec<(NaiveTime, NaiveTime, &TimetableEntry)> = timetable
+        .iter()
+        .flat_map(|entry| {
+            entry
+                .timings()
+                .into_iter()
+                .filter(|timing| timing.weekday == weekday && timing.end > time)
+                .map(move |timing| (timing.start, timing.end, entry))
+        })
+        .collect();
+    classes.sort_by_key(|(start, _, _)| *start);
+
+    if classes.is_empty() {
+        return vec![];
+    }
+
+    let mut lines = vec![];
+
+    match classes.iter().find(|(start, _, _)| *start > time) {
+        Some((start, _, entry)) => lines.push(Spans::from(vec![
+            Span::styled("Next: ", label_style()),
+            Span::raw(format!(
+                "{} in {}",
+                entry.course_code,
+                format_countdown(*start - time)
+            )),
+        ])),
+        None => lines.push(Spans::from(vec![
+            Span::styled("Last class of the day", label_style()),
+            Span::raw(" is in progress."),
+        ])),
+    }
+    lines.push(Spans::from(""));
+
+    for (start, end, entry) in classes {
+        let style = if start <= time {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default()
+        };
+
+        lines.push(Spans::from(Span::styled(
+            format!(
+                "{}-{}  {:<9} {} ({})",
+                start.format("%H:%M"),
+                end.format("%H:%M"),
+                entry.course_code,
+                entry.course_title,
+                entry.venue
+            ),
+            style,
+        )));
+    }
+
+    lines
+}
+
+fn attendance_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    let attendance = match data
+        .global_semester()
+        .and_then(|semester| data.attendance.get(&semester.id))
+    {
+        Some(attendance) => attendance,
+        None => return vec![],
+    };
+
+    attendance
+        .iter()
+        .filter(|entry| entry.is_at_risk())
+        .map(|entry| {
+            Spans::from(vec![
+                Span::styled(
+                    format!("{:>6.2}% ", entry.percentage),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::raw(format!(
+                    "{:<9} {} ({}/{})",
+                    entry.course_code,
+                    entry.course_title,
+                    entry.attended_classes,
+                    entry.total_classes
+                )),
+            ])
+        })
+        .collect()
+}
+
+fn marks_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    let marks = match data
+        .global_semester()
+        .and_then(|semester| data.marks.get(&semester.id))
+    {
+        Some(marks) => marks,
+        None => return vec![],
+    };
+
+    marks
+        .iter()
+        .filter_map(|course| {
+            let component = course.latest_component()?;
+
+            Some(Spans::from(vec![
+                Span::styled(format!("{:<9} ", course.course_code), label_style()),
+                Span::raw(format!(
+                    "{}: {}/{}",
+                    component.title,
+                    component.scored_mark.unwrap_or_default(),
+                    component.max_mark
+                )),
+            ]))
+        })
+        .collect()
+}
+
+fn messages_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    data.unread_messages()
+        .into_iter()
+        .map(|message| {
+            Spans::from(vec![
+                Span::styled(format!("{} ", message.course), label_style()),
+                Span::raw(message.message.clone()),
+            ])
+        })
+        .collect()
+}
+
+fn spotlight_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    data.spotlight
+        .iter()
+        .take(SPOTLIGHT_ITEMS)
+        .map(|item| {
+            Spans::from(vec![
+                Span::styled(format!("[{}] ", item.category), label_style()),
+                Span::raw(item.title.clone()),
+                Span::styled(
+                    format!("  {}", item.posted_on),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// The latest changes detected between fetches, newest first.
+fn whats_new_lines(data: &VtopData) -> Vec<Spans<'static>> {
+    data.changes
+        .iter()
+        .rev()
+        .take(WHATS_NEW_ITEMS)
+        .map(|change| {
+            Spans::from(vec![
+                Span::styled(
+                    format!("{} ", change.detected_at.format("%d-%b %H:%M")),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(change.kind.to_string()),
+            ])
+        })
+        .collect()
+}
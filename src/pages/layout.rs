@@ -0,0 +1,246 @@
+use tui::layout::{Constraint, Direction, Layout, Rect};
+
+/// How a container block splits its area between its inner blocks.
+///
+/// The area is split into chunks, one for every inner block, unless a chunk is split
+/// again by a nested layout between several inner blocks in a row. Chunks without a
+/// constraint share what the constrained ones leave equally, so the default layout
+/// stacks the inner blocks vertically in equal parts.
+#[derive(Clone, Debug)]
+pub struct BlockLayout {
+    pub direction: Direction,
+    /// Constraints of the chunks, in order.
+    pub constraints: Vec<Constraint>,
+    /// Cells left empty on every side, inside the border of the container.
+    pub margin: u16,
+    pub responsive: Option<ResponsiveRule>,
+    /// Layouts splitting chunks again, by the index of the chunk.
+    pub nested: Vec<(usize, BlockLayout)>,
+    /// Constraints of chunks while their inner block is selected, by the index of the
+    /// chunk.
+    pub selected_constraints: Vec<(usize, Constraint)>,
+}
+
+/// Switches the direction of a layout when its area is narrower than `below_width`
+/// columns, like stacking side by side blocks on small terminals.
+#[derive(Clone, Debug)]
+pub struct ResponsiveRule {
+    pub below_width: u16,
+    pub direction: Direction,
+}
+
+impl Default for BlockLayout {
+    fn default() -> Self {
+        BlockLayout {
+            direction: Direction::Vertical,
+            constraints: vec![],
+            margin: 0,
+            responsive: None,
+            nested: vec![],
+            selected_constraints: vec![],
+        }
+    }
+}
+
+impl BlockLayout {
+    pub fn vertical(constraints: Vec<Constraint>) -> BlockLayout {
+        BlockLayout {
+            constraints,
+            ..BlockLayout::default()
+        }
+    }
+
+    pub fn horizontal(constraints: Vec<Constraint>) -> BlockLayout {
+        BlockLayout {
+            direction: Direction::Horizontal,
+            constraints,
+            ..BlockLayout::default()
+        }
+    }
+
+    pub fn margin(mut self, margin: u16) -> BlockLayout {
+        self.margin = margin;
+        self
+    }
+
+    /// Stacks the inner blocks vertically when the area is narrower than `width`.
+    pub fn vertical_below(mut self, width: u16) -> BlockLayout {
+        self.responsive = Some(ResponsiveRule {
+            below_width: width,
+            direction: Direction::Vertical,
+        });
+        self
+    }
+
+    /// Splits the chunk at `index` again with the layout, between as many inner blocks as
+    /// the layout has constraints, like a row of side by side blocks in a vertical
+    /// layout.
+    pub fn nest(mut self, index: usize, layout: BlockLayout) -> BlockLayout {
+        self.nested.push((index, layout));
+        self
+    }
+
+    /// Gives the chunk at `index` the constraint while its inner block is selected, like
+    /// a selector which only lists its items while it is selected.
+    pub fn when_selected(mut self, index: usize, constraint: Constraint) -> BlockLayout {
+        self.selected_constraints.push((index, constraint));
+        self
+    }
+
+    /// Splits the area between `count` inner blocks, returning the area of every inner
+    /// block. `selected_block` is the index of the selected inner block, if any.
+    pub fn split(&self, area: Rect, count: usize, selected_block: Option<usize>) -> Vec<Rect> {
+        // Index of the first inner block and number of inner blocks of every chunk.
+        let mut chunks: Vec<(usize, usize)> = vec![];
+        let mut blocks = 0;
+        while blocks < count {
+            let size = self
+                .nested_layout(chunks.len())
+                .map_or(1, |layout| layout.constraints.len().max(1))
+                .min(count - blocks);
+            chunks.push((blocks, size));
+            blocks += size;
+        }
+
+        if chunks.is_empty() {
+            return vec![];
+        }
+
+        let direction = match &self.responsive {
+            Some(rule) if area.width < rule.below_width => rule.direction.clone(),
+            _ => self.direction.clone(),
+        };
+
+        let constrained = self.constraints.len().min(chunks.len());
+        let mut constraints = chunks[..constrained]
+            .iter()
+            .enumerate()
+            .map(|(index, (first, size))| {
+                self.selected_constraints
+                    .iter()
+                    .find(|(chunk, _)| *chunk == index)
+                    .filter(|_| *size == 1 && selected_block == Some(*first))
+                    .map_or(self.constraints[index], |(_, constraint)| *constraint)
+            })
+            .collect::<Vec<Constraint>>();
+
+        // Ratios are of the whole area, so the unconstrained chunks split what is left in
+        // a second pass.
+        let unconstrained = (chunks.len() - constrained) as u32;
+        if unconstrained > 0 {
+            constraints.push(Constraint::Min(0));
+        }
+
+        let layout = Layout::default().direction(direction);
+        let mut areas = layout
+            .clone()
+            .constraints(constraints)
+            .margin(self.margin)
+            .split(area);
+        if unconstrained > 0 {
+            let rest = areas.pop().unwrap();
+            areas.extend(
+                layout
+                    .constraints(
+                        (0..unconstrained)
+                            .map(|_| Constraint::Ratio(1, unconstrained))
+                            .collect::<Vec<Constraint>>(),
+                    )
+                    .split(rest),
+            );
+        }
+
+        chunks
+            .iter()
+            .zip(areas)
+            .enumerate()
+            .flat_map(
+                |(index, ((first, size), area))| match self.nested_layout(index) {
+                    Some(layout) => layout.split(
+                        area,
+                        *size,
+                        selected_block
+                            .and_then(|block| block.checked_sub(*first))
+                            .filter(|block| block < size),
+                    ),
+                    None => vec![area],
+                },
+            )
+            .collect()
+    }
+
+    fn nested_layout(&self, index: usize) -> Option<&BlockLayout> {
+        self.nested
+            .iter()
+            .find(|(chunk, _)| *chunk == index)
+            .map(|(_, layout)| layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 100,
+        height: 40,
+    };
+
+    #[test]
+    fn shares_what_constrained_blocks_leave() {
+        let layout = BlockLayout::vertical(vec![Constraint::Length(10)]);
+
+        assert_eq!(
+            layout.split(AREA, 3, None),
+            vec![
+                Rect::new(0, 0, 100, 10),
+                Rect::new(0, 10, 100, 15),
+                Rect::new(0, 25, 100, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_nested_chunks_between_several_blocks() {
+        let layout = BlockLayout::vertical(vec![Constraint::Length(10), Constraint::Min(1)])
+            .margin(1)
+            .nest(
+                1,
+                BlockLayout::horizontal(vec![
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(60),
+                ]),
+            );
+
+        assert_eq!(
+            layout.split(AREA, 3, None),
+            vec![
+                Rect::new(1, 1, 98, 10),
+                Rect::new(1, 11, 39, 28),
+                Rect::new(40, 11, 59, 28),
+            ]
+        );
+    }
+
+    #[test]
+    fn grows_the_selected_block() {
+        let layout = BlockLayout::vertical(vec![Constraint::Length(3), Constraint::Min(1)])
+            .when_selected(0, Constraint::Length(10));
+
+        assert_eq!(layout.split(AREA, 2, None)[0].height, 3);
+        assert_eq!(layout.split(AREA, 2, Some(1))[0].height, 3);
+        assert_eq!(layout.split(AREA, 2, Some(0))[0].height, 10);
+    }
+
+    #[test]
+    fn stacks_blocks_below_the_width() {
+        let layout = BlockLayout::horizontal(vec![]).vertical_below(120);
+
+        assert_eq!(
+            layout.split(AREA, 2, None),
+            vec![Rect::new(0, 0, 100, 20), Rect::new(0, 20, 100, 20)]
+        );
+    }
+}
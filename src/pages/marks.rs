@@ -0,0 +1,181 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::DataTable,
+    input::Key,
+    models::{CourseMarks, VtopData},
+    network::NetworkEvent,
+};
+
+use super::{
+    export_hint, handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockLayout,
+    BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const COURSES_BLOCK: usize = 1;
+const COMPONENTS_BLOCK: usize = 2;
+
+pub struct MarksPage {}
+
+impl MarksPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let courses = Block::default("Courses".to_string(), BlockType::ListBlock);
+        let components = Block::default("Marks".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(courses).unwrap();
+        block.append_inner_block(components).unwrap();
+
+        block.set_layout(semester_page_layout(vec![Constraint::Min(1)]).nest(
+            1,
+            BlockLayout::horizontal(vec![Constraint::Percentage(45), Constraint::Percentage(55)]),
+        ));
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetMarks {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        block.handle_input(key);
+        MarksPage::refresh(block, data);
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            return MarksPage::missing_data(block, data);
+        }
+
+        if block.selected_block() == Some(COURSES_BLOCK) && key == Key::Char('x') {
+            return vec![NetworkEvent::ExportTable {
+                table: DataTable::Marks,
+                semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+            }];
+        }
+
+        vec![]
+    }
+
+    /// Fetches the marks of the selected semester if they aren't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.marks.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetMarks {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let items = marks(block, data)
+            .iter()
+            .map(|course| {
+                format!(
+                    "{:<9} {:<40.40} {}",
+                    course.course_code, course.course_title, course.course_type
+                )
+            })
+            .collect();
+
+        if let Some(courses_block) = block.get_inner_block_mut(COURSES_BLOCK) {
+            courses_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let components = match hovered_course(block_self, &app.state.data) {
+            Some(course) => {
+                let mut lines = vec![
+                    Spans::from(vec![
+                        Span::styled("Faculty: ", label_style),
+                        Span::raw(format!("{} ({})", course.faculty, course.slot)),
+                    ]),
+                    Spans::from(""),
+                ];
+
+                for component in course.components.iter() {
+                    let scored = match (component.scored_mark, component.weightage_mark) {
+                        (Some(scored), Some(weightage)) => format!(
+                            "{}/{}  ({}/{})",
+                            scored, component.max_mark, weightage, component.weightage
+                        ),
+                        (Some(scored), None) => format!("{}/{}", scored, component.max_mark),
+                        _ => format!("-/{}", component.max_mark),
+                    };
+
+                    let mut spans = vec![
+                        Span::styled(format!("{}: ", component.title), label_style),
+                        Span::raw(scored),
+                    ];
+                    if let Some(class_average) = component.class_average {
+                        spans.push(Span::styled(
+                            format!("  class average {}", class_average),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+
+                    lines.push(Spans::from(spans));
+                }
+
+                lines.push(Spans::from(""));
+                lines.push(export_hint(app.config.export_format));
+
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, &app.state.data)
+            .placeholder(
+                &[COURSES_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No marks posted this semester."
+                },
+            )
+            .text(&[COMPONENTS_BLOCK], components)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn marks<'a>(block: &Block, data: &'a VtopData) -> &'a [CourseMarks] {
+    selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.marks.get(&semester.id))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn hovered_course<'a>(block: &Block, data: &'a VtopData) -> Option<&'a CourseMarks> {
+    let index = block.get_inner_block(COURSES_BLOCK)?.hovered_item()?;
+    marks(block, data).get(index)
+}
@@ -1,14 +1,67 @@
 use std::{
+    collections::HashMap,
     fmt::{self, Display},
     slice::Iter,
 };
 
-use tui::{backend::Backend, layout::Rect, widgets::Borders, Frame};
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::Borders,
+    Frame,
+};
 
-use crate::{app::App, input::Key};
+use crate::{app::App, export::ExportFormat, input::Key, models::VtopData, network::NetworkEvent};
+pub use academic_calendar::AcademicCalendarPage;
+pub use button::Button;
+pub use class_attendance::ClassAttendancePage;
+pub use class_messages::ClassMessagesPage;
+pub use course_page::CoursePage;
 pub use debug::DebugPage;
+pub use downloads::DownloadsPage;
+pub use exam_schedule::ExamSchedulePage;
+pub use form::{Form, Validator};
+pub use grades::GradesPage;
+pub use home::HomePage;
+pub use layout::{BlockLayout, ResponsiveRule};
+pub use marks::MarksPage;
+pub use renderer::BlockRenderer;
+pub use room_information::RoomInformationPage;
+use semester_selector::{
+    handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector,
+};
+pub use spotlight::SpotlightPage;
+pub use table::{SortOrder, Table};
+pub use text_area::TextArea;
+pub use text_input::TextInput;
+pub use text_view::{TextView, WrapMode};
+pub use time_table::TimeTablePage;
 
+mod academic_calendar;
+mod button;
+mod class_attendance;
+mod class_messages;
+mod course_page;
 mod debug;
+mod downloads;
+mod exam_schedule;
+mod form;
+mod grades;
+mod home;
+mod layout;
+mod marks;
+mod renderer;
+mod room_information;
+mod semester_selector;
+mod spotlight;
+mod table;
+mod text_area;
+mod text_input;
+mod text_view;
+mod time_table;
 
 #[derive(Copy, Clone)]
 pub enum Page {
@@ -20,15 +73,18 @@ pub enum Page {
     ClassAttendance,
     CoursePage,
     Marks,
+    ExamSchedule,
+    AcademicCalendar,
     Grades,
     GradeHistory,
     RoomInformation,
+    Downloads,
     Debug,
 }
 
 impl Page {
     pub fn iterator() -> Iter<'static, Page> {
-        static PAGES: [Page; 12] = [
+        static PAGES: [Page; 15] = [
             Page::Home,
             Page::Spotlight,
             Page::FacultyInfo,
@@ -37,9 +93,12 @@ impl Page {
             Page::ClassAttendance,
             Page::CoursePage,
             Page::Marks,
+            Page::ExamSchedule,
+            Page::AcademicCalendar,
             Page::Grades,
             Page::GradeHistory,
             Page::RoomInformation,
+            Page::Downloads,
             Page::Debug,
         ];
         PAGES.iter()
@@ -57,15 +116,41 @@ impl Display for Page {
             Page::ClassAttendance => "Class Attendance",
             Page::CoursePage => "Course Page",
             Page::Marks => "Marks",
+            Page::ExamSchedule => "Exam Schedule",
+            Page::AcademicCalendar => "Academic Calendar",
             Page::Grades => "Grades",
             Page::GradeHistory => "Grade History",
             Page::RoomInformation => "Room Information",
+            Page::Downloads => "Downloads",
             Page::Debug => "Debug Page",
         };
         write!(f, "{}", str)
     }
 }
 
+/// What a page asks the app to do in response to an input.
+pub enum PageAction {
+    Network(NetworkEvent),
+    OpenPage(Page),
+}
+
+/// What activating a button asks the page owning it to do.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ButtonAction {
+    /// Reported by buttons whose page hasn't given them an action.
+    #[default]
+    None,
+    DownloadMaterials,
+    SubmitForm,
+}
+
+/// What a block reports to the page owning it in response to an input.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BlockEvent {
+    /// A button was activated, with the action of the button.
+    ButtonPressed(ButtonAction),
+}
+
 pub struct PageBlock {
     pub page: Page,
     pub block: Block,
@@ -82,11 +167,129 @@ impl PageBlock {
 
     fn init_page(mut self) -> Self {
         match self.page {
+            Page::Home => HomePage::fill_inner_blocks(&mut self.block),
+            Page::Spotlight => SpotlightPage::fill_inner_blocks(&mut self.block),
+            Page::ClassMessages => ClassMessagesPage::fill_inner_blocks(&mut self.block),
+            Page::TimeTable => TimeTablePage::fill_inner_blocks(&mut self.block),
+            Page::ClassAttendance => ClassAttendancePage::fill_inner_blocks(&mut self.block),
+            Page::Marks => MarksPage::fill_inner_blocks(&mut self.block),
+            Page::ExamSchedule => ExamSchedulePage::fill_inner_blocks(&mut self.block),
+            Page::AcademicCalendar => AcademicCalendarPage::fill_inner_blocks(&mut self.block),
+            Page::Grades => GradesPage::fill_inner_blocks(&mut self.block),
+            Page::CoursePage => CoursePage::fill_inner_blocks(&mut self.block),
+            Page::RoomInformation => RoomInformationPage::fill_inner_blocks(&mut self.block),
+            Page::Downloads => DownloadsPage::fill_inner_blocks(&mut self.block),
             Page::Debug => DebugPage::fill_inner_blocks(&mut self.block),
             _ => (),
         }
 
-        return self;
+        self
+    }
+
+    /// Returns the network events needed to fetch the data shown when the page is opened.
+    pub fn on_open(&mut self, data: &VtopData) -> Vec<NetworkEvent> {
+        self.refresh(data);
+
+        match self.page {
+            Page::Home => vec![NetworkEvent::RefreshDashboard],
+            Page::Spotlight => vec![NetworkEvent::GetSpotlight],
+            Page::ClassMessages => vec![NetworkEvent::GetClassMessages],
+            Page::TimeTable => TimeTablePage::on_open(&self.block, data),
+            Page::ClassAttendance => ClassAttendancePage::on_open(&self.block, data),
+            Page::Marks => MarksPage::on_open(&self.block, data),
+            Page::ExamSchedule => ExamSchedulePage::on_open(&self.block, data),
+            Page::AcademicCalendar => AcademicCalendarPage::on_open(&self.block, data),
+            Page::Grades => GradesPage::on_open(&self.block, data),
+            Page::CoursePage => CoursePage::on_open(&self.block, data),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the network events fetching the data of the page's semester which isn't
+    /// fetched yet, for when the global semester changes.
+    pub fn missing_data(&self, data: &VtopData) -> Vec<NetworkEvent> {
+        match self.page {
+            Page::TimeTable => TimeTablePage::missing_data(&self.block, data),
+            Page::ClassAttendance => ClassAttendancePage::missing_data(&self.block, data),
+            Page::Marks => MarksPage::missing_data(&self.block, data),
+            Page::ExamSchedule => ExamSchedulePage::missing_data(&self.block, data),
+            Page::AcademicCalendar => AcademicCalendarPage::missing_data(&self.block, data),
+            Page::Grades => GradesPage::missing_data(&self.block, data),
+            Page::CoursePage => CoursePage::missing_data(&self.block, data),
+            _ => vec![],
+        }
+    }
+
+    /// Passes the key to the page's blocks and returns the actions requested by the
+    /// page in response.
+    pub fn handle_input(&mut self, key: Key, data: &mut VtopData) -> Vec<PageAction> {
+        let network_events = match self.page {
+            Page::Home => return HomePage::handle_input(&mut self.block, key, data),
+            Page::ClassMessages => ClassMessagesPage::handle_input(&mut self.block, key, data),
+            Page::TimeTable => TimeTablePage::handle_input(&mut self.block, key, data),
+            Page::ClassAttendance => ClassAttendancePage::handle_input(&mut self.block, key, data),
+            Page::Marks => MarksPage::handle_input(&mut self.block, key, data),
+            Page::ExamSchedule => ExamSchedulePage::handle_input(&mut self.block, key, data),
+            Page::AcademicCalendar => {
+                AcademicCalendarPage::handle_input(&mut self.block, key, data)
+            }
+            Page::Grades => GradesPage::handle_input(&mut self.block, key, data),
+            Page::CoursePage => CoursePage::handle_input(&mut self.block, key, data),
+            Page::RoomInformation => RoomInformationPage::handle_input(&mut self.block, key, data),
+            Page::Downloads => DownloadsPage::handle_input(&mut self.block, key, data),
+            Page::Debug => DebugPage::handle_input(&mut self.block, key),
+            _ => {
+                self.block.handle_input(key);
+                vec![]
+            }
+        };
+
+        network_events
+            .into_iter()
+            .map(PageAction::Network)
+            .collect()
+    }
+
+    /// Activates the button clicked at the cell, if any, and returns the actions requested
+    /// by the page in response.
+    pub fn handle_click(&mut self, column: u16, row: u16, data: &mut VtopData) -> Vec<PageAction> {
+        match self.block.click(column, row) {
+            Some(event) => self.handle_event(event, data),
+            None => vec![],
+        }
+    }
+
+    /// Passes an event of the page's blocks to the page and returns the actions requested
+    /// by the page in response.
+    fn handle_event(&mut self, event: BlockEvent, data: &mut VtopData) -> Vec<PageAction> {
+        let network_events = match self.page {
+            Page::CoursePage => CoursePage::handle_event(&mut self.block, event, data),
+            Page::Debug => DebugPage::handle_event(&mut self.block, event),
+            _ => vec![],
+        };
+
+        network_events
+            .into_iter()
+            .map(PageAction::Network)
+            .collect()
+    }
+
+    /// Updates the page's blocks with the latest data fetched from VTOP.
+    pub fn refresh(&mut self, data: &VtopData) {
+        match self.page {
+            Page::Spotlight => SpotlightPage::refresh(&mut self.block, data),
+            Page::ClassMessages => ClassMessagesPage::refresh(&mut self.block, data),
+            Page::TimeTable => TimeTablePage::refresh(&mut self.block, data),
+            Page::ClassAttendance => ClassAttendancePage::refresh(&mut self.block, data),
+            Page::Marks => MarksPage::refresh(&mut self.block, data),
+            Page::ExamSchedule => ExamSchedulePage::refresh(&mut self.block, data),
+            Page::AcademicCalendar => AcademicCalendarPage::refresh(&mut self.block, data),
+            Page::Grades => GradesPage::refresh(&mut self.block, data),
+            Page::CoursePage => CoursePage::refresh(&mut self.block, data),
+            Page::RoomInformation => RoomInformationPage::refresh(&mut self.block, data),
+            Page::Downloads => DownloadsPage::refresh(&mut self.block, data),
+            _ => (),
+        }
     }
 
     pub fn draw_page<B>(&self, f: &mut Frame<B>, app: &App, layout_chunk: Rect)
@@ -94,6 +297,18 @@ impl PageBlock {
         B: Backend,
     {
         match self.page {
+            Page::Home => HomePage::draw(f, app, layout_chunk),
+            Page::Spotlight => SpotlightPage::draw(f, app, layout_chunk),
+            Page::ClassMessages => ClassMessagesPage::draw(f, app, layout_chunk),
+            Page::TimeTable => TimeTablePage::draw(f, app, layout_chunk),
+            Page::ClassAttendance => ClassAttendancePage::draw(f, app, layout_chunk),
+            Page::Marks => MarksPage::draw(f, app, layout_chunk),
+            Page::ExamSchedule => ExamSchedulePage::draw(f, app, layout_chunk),
+            Page::AcademicCalendar => AcademicCalendarPage::draw(f, app, layout_chunk),
+            Page::Grades => GradesPage::draw(f, app, layout_chunk),
+            Page::CoursePage => CoursePage::draw(f, app, layout_chunk),
+            Page::RoomInformation => RoomInformationPage::draw(f, app, layout_chunk),
+            Page::Downloads => DownloadsPage::draw(f, app, layout_chunk),
             Page::Debug => DebugPage::draw(f, app, layout_chunk),
             _ => (),
         }
@@ -102,23 +317,45 @@ impl PageBlock {
 
 pub enum BlockType {
     InputBlock,
+    TextAreaBlock,
     ParagraphBlock,
+    ListBlock,
+    TableBlock,
     Button,
     ContainerBlock,
+    /// A container block validating its input and text area blocks.
+    FormBlock,
 }
 
 pub enum BlockContent {
     InputBlock {
-        input_text: String,
+        input: TextInput,
+    },
+    TextAreaBlock {
+        text_area: TextArea,
     },
     ParagraphBlock {
-        text: String,
+        text: TextView,
+    },
+    ListBlock {
+        items: Vec<String>,
+        hovered_item: usize,
+        chosen_item: Option<usize>,
+        marked_items: Vec<bool>,
+        multi_select: bool,
+    },
+    TableBlock {
+        table: Table,
+    },
+    Button {
+        button: Button,
     },
-    Button,
     ContainerBlock {
         inner_blocks: Vec<Block>,
         selected_block: Option<usize>,
         hovered_block: Option<usize>,
+        layout: BlockLayout,
+        form: Option<Form>,
     },
 }
 
@@ -141,6 +378,54 @@ pub struct Block {
     pub content: BlockContent,
 }
 
+/// Returns the widget framing the `index`th inner block of a container block, with its
+/// border colored when the inner block is selected or hovered.
+pub fn inner_block_widget(container: &Block, index: usize) -> tui::widgets::Block<'static> {
+    let inner_block = container.get_inner_block(index);
+
+    tui::widgets::Block::default()
+        .title(Span::styled(
+            format!(" {} ", inner_block.map(Block::title).unwrap_or_default()),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(inner_block.map(Block::border).unwrap_or(Borders::NONE))
+        .border_style(inner_block_border_style(container, index))
+}
+
+/// Border style of the `index`th inner block of a container block: light cyan when the
+/// inner block is selected and magenta when it is hovered.
+fn inner_block_border_style(container: &Block, index: usize) -> Style {
+    let (selected_block, hovered_block) = match container.content {
+        BlockContent::ContainerBlock {
+            selected_block,
+            hovered_block,
+            ..
+        } => (selected_block, hovered_block),
+        _ => (None, None),
+    };
+
+    if selected_block == Some(index) {
+        Style::default().fg(Color::LightCyan)
+    } else if hovered_block == Some(index) {
+        Style::default().fg(Color::Magenta)
+    } else {
+        Style::default()
+    }
+}
+
+/// Hint line for the pages whose table is exported with 'x'.
+pub fn export_hint(format: ExportFormat) -> Spans<'static> {
+    Spans::from(Span::styled(
+        format!(
+            "'x' exports the table as {} to the download directory.",
+            format.extension()
+        ),
+        Style::default().fg(Color::DarkGray),
+    ))
+}
+
 impl Block {
     pub fn default_raw(block_type: BlockType) -> Block {
         Block {
@@ -148,16 +433,40 @@ impl Block {
             border: BlockBorder::NoBorder,
             content: match block_type {
                 BlockType::InputBlock => BlockContent::InputBlock {
-                    input_text: String::new(),
+                    input: TextInput::default(),
+                },
+                BlockType::TextAreaBlock => BlockContent::TextAreaBlock {
+                    text_area: TextArea::default(),
                 },
                 BlockType::ParagraphBlock => BlockContent::ParagraphBlock {
-                    text: String::new(),
+                    text: TextView::default(),
+                },
+                BlockType::ListBlock => BlockContent::ListBlock {
+                    items: vec![],
+                    hovered_item: 0,
+                    chosen_item: None,
+                    marked_items: vec![],
+                    multi_select: false,
+                },
+                BlockType::TableBlock => BlockContent::TableBlock {
+                    table: Table::default(),
+                },
+                BlockType::Button => BlockContent::Button {
+                    button: Button::new(String::new()),
                 },
-                BlockType::Button => BlockContent::Button,
                 BlockType::ContainerBlock => BlockContent::ContainerBlock {
                     inner_blocks: vec![],
                     selected_block: None,
                     hovered_block: None,
+                    layout: BlockLayout::default(),
+                    form: None,
+                },
+                BlockType::FormBlock => BlockContent::ContainerBlock {
+                    inner_blocks: vec![],
+                    selected_block: None,
+                    hovered_block: None,
+                    layout: BlockLayout::default(),
+                    form: Some(Form::default()),
                 },
             },
         }
@@ -165,10 +474,13 @@ impl Block {
 
     pub fn default(title: String, block_type: BlockType) -> Block {
         let mut block = Block::default_raw(block_type);
+        if let BlockContent::Button { ref mut button } = block.content {
+            button.label = title.clone();
+        }
         block.add_title(title);
         block.add_border();
 
-        return block;
+        block
     }
 
     pub fn add_title(&mut self, title: String) {
@@ -229,6 +541,68 @@ impl Block {
         }
     }
 
+    /// Appends an input or text area block to a form block as a field checked by the
+    /// validators.
+    pub fn append_form_field(
+        &mut self,
+        block: Block,
+        validators: Vec<Validator>,
+    ) -> Result<(), &str> {
+        match self.content {
+            BlockContent::ContainerBlock {
+                ref mut inner_blocks,
+                form: Some(ref mut form),
+                ..
+            } => {
+                form.add_field(inner_blocks.len(), validators);
+                inner_blocks.push(block);
+                Ok(())
+            }
+            _ => Err("Can only append fields to form blocks."),
+        }
+    }
+
+    /// Appends the button submitting a form block, enabled while the fields are valid.
+    pub fn append_form_submit(&mut self, button: Block) -> Result<(), &str> {
+        let action = match button.content {
+            BlockContent::Button { ref button } => button.action,
+            _ => return Err("Can only submit forms with button blocks."),
+        };
+
+        match self.content {
+            BlockContent::ContainerBlock {
+                ref mut inner_blocks,
+                form: Some(ref mut form),
+                ..
+            } => {
+                form.set_submit(inner_blocks.len(), action);
+                inner_blocks.push(button);
+                form.update_submit(inner_blocks);
+                Ok(())
+            }
+            _ => Err("Can only append a submit button to form blocks."),
+        }
+    }
+
+    pub fn form(&self) -> Option<&Form> {
+        match &self.content {
+            BlockContent::ContainerBlock { form, .. } => form.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Values of the fields of a form block, by the titles of their blocks.
+    pub fn form_values(&self) -> HashMap<String, String> {
+        match &self.content {
+            BlockContent::ContainerBlock {
+                inner_blocks,
+                form: Some(form),
+                ..
+            } => form.values(inner_blocks),
+            _ => HashMap::new(),
+        }
+    }
+
     pub fn get_inner_blocks_mut(&mut self) -> Result<&mut Vec<Block>, &str> {
         if let BlockContent::ContainerBlock {
             ref mut inner_blocks,
@@ -252,6 +626,330 @@ impl Block {
         }
     }
 
+    pub fn get_inner_block_mut(&mut self, index: usize) -> Option<&mut Block> {
+        self.get_inner_blocks_mut().ok()?.get_mut(index)
+    }
+
+    pub fn get_inner_block(&self, index: usize) -> Option<&Block> {
+        self.get_inner_blocks().ok()?.get(index)
+    }
+
+    /// Sets how a container block splits its area between its inner blocks.
+    pub fn set_layout(&mut self, new_layout: BlockLayout) {
+        if let BlockContent::ContainerBlock { ref mut layout, .. } = self.content {
+            *layout = new_layout;
+        }
+    }
+
+    pub fn layout(&self) -> Option<&BlockLayout> {
+        if let BlockContent::ContainerBlock { ref layout, .. } = self.content {
+            Some(layout)
+        } else {
+            None
+        }
+    }
+
+    /// Index of the selected inner block of a container block.
+    pub fn selected_block(&self) -> Option<usize> {
+        if let BlockContent::ContainerBlock { selected_block, .. } = self.content {
+            selected_block
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the items of a list block. The hovered, chosen and marked items are kept
+    /// if the number of items doesn't change.
+    pub fn set_list_items(&mut self, new_items: Vec<String>) {
+        if let BlockContent::ListBlock {
+            ref mut items,
+            ref mut hovered_item,
+            ref mut chosen_item,
+            ref mut marked_items,
+            ..
+        } = self.content
+        {
+            if items.len() != new_items.len() {
+                *hovered_item = 0;
+                *chosen_item = None;
+                *marked_items = vec![false; new_items.len()];
+            }
+            *items = new_items;
+        }
+    }
+
+    pub fn set_paragraph_text(&mut self, new_text: String) {
+        if let BlockContent::ParagraphBlock { ref mut text } = self.content {
+            text.set_text(&new_text);
+        }
+    }
+
+    /// Sets the text of a paragraph block as styled lines.
+    pub fn set_paragraph_lines(&mut self, lines: Vec<Spans<'static>>) {
+        if let BlockContent::ParagraphBlock { ref mut text } = self.content {
+            text.set_lines(lines);
+        }
+    }
+
+    pub fn set_wrap(&mut self, wrap: WrapMode) {
+        if let BlockContent::ParagraphBlock { ref mut text } = self.content {
+            text.wrap = wrap;
+        }
+    }
+
+    /// Scrolls a paragraph block back to its first row, like when it shows new text.
+    pub fn scroll_to_top(&mut self) {
+        if let BlockContent::ParagraphBlock { ref mut text } = self.content {
+            text.scroll_to_top();
+        }
+    }
+
+    pub fn input_text(&self) -> String {
+        if let BlockContent::InputBlock { ref input } = self.content {
+            input.text().to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub fn set_input_text(&mut self, text: String) {
+        if let BlockContent::InputBlock { ref mut input } = self.content {
+            input.set_text(text);
+        }
+    }
+
+    /// Shows the text of an input block as `•`s, for passwords.
+    pub fn mask_input(&mut self) {
+        if let BlockContent::InputBlock { ref mut input } = self.content {
+            input.masked = true;
+        }
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: String) {
+        match self.content {
+            BlockContent::InputBlock { ref mut input } => input.placeholder = placeholder,
+            BlockContent::TextAreaBlock { ref mut text_area } => {
+                text_area.placeholder = placeholder
+            }
+            _ => (),
+        }
+    }
+
+    pub fn text_area_text(&self) -> String {
+        if let BlockContent::TextAreaBlock { ref text_area } = self.content {
+            text_area.text().to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Limits the number of chars a text area block takes.
+    pub fn set_max_length(&mut self, max_length: usize) {
+        if let BlockContent::TextAreaBlock { ref mut text_area } = self.content {
+            text_area.max_length = Some(max_length);
+        }
+    }
+
+    /// Sets the titles of the columns of a table block.
+    pub fn set_table_header(&mut self, header: Vec<String>) {
+        if let BlockContent::TableBlock { ref mut table } = self.content {
+            table.set_header(header);
+        }
+    }
+
+    pub fn set_table_rows(&mut self, rows: Vec<Vec<String>>) {
+        if let BlockContent::TableBlock { ref mut table } = self.content {
+            table.set_rows(rows);
+        }
+    }
+
+    /// Index of the selected row of a table block, in the rows as set.
+    pub fn selected_row(&self) -> Option<usize> {
+        if let BlockContent::TableBlock { ref table } = self.content {
+            table.selected_row()
+        } else {
+            None
+        }
+    }
+
+    pub fn set_button_label(&mut self, label: String) {
+        if let BlockContent::Button { ref mut button } = self.content {
+            button.label = label;
+        }
+    }
+
+    /// Sets the action a button block reports to its page when it is activated.
+    pub fn set_button_action(&mut self, action: ButtonAction) {
+        if let BlockContent::Button { ref mut button } = self.content {
+            button.action = action;
+        }
+    }
+
+    /// Lets the char activate a button block, shown in brackets in its label.
+    pub fn set_accelerator(&mut self, accelerator: char) {
+        if let BlockContent::Button { ref mut button } = self.content {
+            button.accelerator = Some(accelerator);
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if let BlockContent::Button { ref mut button } = self.content {
+            button.enabled = enabled;
+        }
+    }
+
+    /// Activates the enabled button block with the accelerator among the block and its
+    /// inner blocks, if any.
+    fn press_accelerator(&self, ch: char) -> Option<BlockEvent> {
+        match &self.content {
+            BlockContent::Button { button } if button.has_accelerator(ch) => button.press(),
+            BlockContent::ContainerBlock { inner_blocks, .. } => inner_blocks
+                .iter()
+                .find_map(|block| block.press_accelerator(ch)),
+            _ => None,
+        }
+    }
+
+    /// Activates the button block at the cell clicked, if any.
+    pub fn click(&self, column: u16, row: u16) -> Option<BlockEvent> {
+        match &self.content {
+            BlockContent::Button { button } if button.contains(column, row) => button.press(),
+            BlockContent::ContainerBlock { inner_blocks, .. } => inner_blocks
+                .iter()
+                .find_map(|block| block.click(column, row)),
+            _ => None,
+        }
+    }
+
+    /// Pastes the text into the input or text area block being edited, if any.
+    pub fn paste(&mut self, text: &str) {
+        match self.content {
+            BlockContent::InputBlock { ref mut input } => input.paste(text),
+            BlockContent::TextAreaBlock { ref mut text_area } => text_area.paste(text),
+            BlockContent::ContainerBlock {
+                ref mut inner_blocks,
+                selected_block: Some(index),
+                ref form,
+                ..
+            } => {
+                if let Some(block) = inner_blocks.get_mut(index) {
+                    block.paste(text);
+                }
+                if let Some(form) = form {
+                    form.update_submit(inner_blocks);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Whether the selected blocks lead to an input or text area block, which then takes
+    /// every key, including the ones bound globally.
+    pub fn is_editing_text(&self) -> bool {
+        match &self.content {
+            BlockContent::InputBlock { .. } | BlockContent::TextAreaBlock { .. } => true,
+            BlockContent::ContainerBlock {
+                inner_blocks,
+                selected_block: Some(index),
+                ..
+            } => inner_blocks
+                .get(*index)
+                .is_some_and(|block| block.is_editing_text()),
+            _ => false,
+        }
+    }
+
+    pub fn enable_multi_select(&mut self) {
+        if let BlockContent::ListBlock {
+            ref mut multi_select,
+            ..
+        } = self.content
+        {
+            *multi_select = true;
+        }
+    }
+
+    pub fn hovered_item(&self) -> Option<usize> {
+        if let BlockContent::ListBlock {
+            ref items,
+            hovered_item,
+            ..
+        } = self.content
+        {
+            if hovered_item < items.len() {
+                return Some(hovered_item);
+            }
+        }
+
+        None
+    }
+
+    pub fn set_hovered_item(&mut self, item: usize) {
+        if let BlockContent::ListBlock {
+            ref items,
+            ref mut hovered_item,
+            ..
+        } = self.content
+        {
+            if item < items.len() {
+                *hovered_item = item;
+            }
+        }
+    }
+
+    pub fn chosen_item(&self) -> Option<usize> {
+        if let BlockContent::ListBlock { chosen_item, .. } = self.content {
+            chosen_item
+        } else {
+            None
+        }
+    }
+
+    pub fn set_chosen_item(&mut self, item: Option<usize>) {
+        if let BlockContent::ListBlock {
+            ref mut chosen_item,
+            ..
+        } = self.content
+        {
+            *chosen_item = item;
+        }
+    }
+
+    /// Indices of the marked items of a multi-select list block.
+    pub fn marked_items(&self) -> Vec<usize> {
+        if let BlockContent::ListBlock {
+            ref marked_items, ..
+        } = self.content
+        {
+            (0..marked_items.len())
+                .filter(|&index| marked_items[index])
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn clear_marked_items(&mut self) {
+        if let BlockContent::ListBlock {
+            ref mut marked_items,
+            ..
+        } = self.content
+        {
+            marked_items.iter_mut().for_each(|marked| *marked = false);
+        }
+    }
+
+    /// Deselects the selected inner block of a container block, leaving it hovered.
+    pub fn unselect_inner_block(&mut self) {
+        if let BlockContent::ContainerBlock {
+            ref mut selected_block,
+            ..
+        } = self.content
+        {
+            *selected_block = None;
+        }
+    }
+
     pub fn hover_first_block(&mut self) {
         if let BlockContent::ContainerBlock {
             ref inner_blocks,
@@ -259,7 +957,7 @@ impl Block {
             ..
         } = self.content
         {
-            if inner_blocks.len() != 0 {
+            if !inner_blocks.is_empty() {
                 *hovered_block = Some(0);
             }
         }
@@ -300,6 +998,7 @@ impl Block {
             ref mut inner_blocks,
             ref mut selected_block,
             ref mut hovered_block,
+            ..
         } = self.content
         {
             *selected_block = None;
@@ -316,18 +1015,97 @@ impl Block {
             return selected_block.is_some();
         }
 
-        return false;
+        false
+    }
+
+    /// Passes the key to the block, returning the event of the button it activates, if
+    /// any.
+    pub fn handle_input(&mut self, key: Key) -> Option<BlockEvent> {
+        if let BlockContent::InputBlock { ref mut input } = self.content {
+            input.handle_input(key);
+            return None;
+        }
+
+        if let BlockContent::TextAreaBlock { ref mut text_area } = self.content {
+            text_area.handle_input(key);
+            return None;
+        }
+
+        if let BlockContent::TableBlock { ref mut table } = self.content {
+            table.handle_input(key);
+            return None;
+        }
+
+        if let BlockContent::ParagraphBlock { ref mut text } = self.content {
+            text.handle_input(key);
+            return None;
+        }
+
+        if let BlockContent::Button { ref button } = self.content {
+            return match key {
+                Key::Enter => button.press(),
+                _ => None,
+            };
+        }
+
+        if let BlockContent::ListBlock {
+            ref items,
+            ref mut hovered_item,
+            ref mut chosen_item,
+            ref mut marked_items,
+            multi_select,
+        } = self.content
+        {
+            if items.is_empty() {
+                return None;
+            }
+
+            match key {
+                Key::Down | Key::Tab => *hovered_item = (*hovered_item + 1) % items.len(),
+                Key::Up | Key::ShiftTab => {
+                    *hovered_item = if *hovered_item == 0 {
+                        items.len() - 1
+                    } else {
+                        *hovered_item - 1
+                    }
+                }
+                Key::Home => *hovered_item = 0,
+                Key::End => *hovered_item = items.len() - 1,
+                Key::Enter => *chosen_item = Some(*hovered_item),
+                Key::Char(' ') if multi_select => {
+                    marked_items[*hovered_item] = !marked_items[*hovered_item]
+                }
+                Key::Char('a') if multi_select => {
+                    let mark = marked_items.iter().any(|marked| !marked);
+                    marked_items.iter_mut().for_each(|marked| *marked = mark);
+                }
+                _ => (),
+            }
+
+            return None;
+        }
+
+        let previous_selected_block = self.selected_block();
+        let event = self.handle_container_input(key);
+        self.handle_form_event(previous_selected_block, event)
     }
 
-    pub fn handle_input(&mut self, key: Key) {
+    fn handle_container_input(&mut self, key: Key) -> Option<BlockEvent> {
+        if let Key::Alt(ch) = key {
+            if let Some(event) = self.press_accelerator(ch) {
+                return Some(event);
+            }
+        }
+
         if let BlockContent::ContainerBlock {
             ref mut inner_blocks,
             ref mut selected_block,
             ref mut hovered_block,
+            ..
         } = self.content
         {
-            if inner_blocks.len() == 0 {
-                return;
+            if inner_blocks.is_empty() {
+                return None;
             }
 
             if hovered_block.is_none() {
@@ -335,6 +1113,29 @@ impl Block {
             }
 
             if selected_block.is_none() {
+                // Buttons are activated right away instead of being selected.
+                if let Key::Enter = key {
+                    if let BlockContent::Button { ref button } =
+                        inner_blocks[hovered_block.unwrap()].content
+                    {
+                        return button.press();
+                    }
+                }
+                // Without Alt, only the buttons of this container are activated, so that
+                // a char can't reach into the containers nested in it.
+                if let Key::Char(ch) = key {
+                    if let Some(event) =
+                        inner_blocks.iter().find_map(|block| match &block.content {
+                            BlockContent::Button { button } if button.has_accelerator(ch) => {
+                                button.press()
+                            }
+                            _ => None,
+                        })
+                    {
+                        return Some(event);
+                    }
+                }
+
                 if let Key::Tab | Key::Right | Key::Down = key {
                     self.hover_block_right();
                 } else if let Key::ShiftTab | Key::Left | Key::Up = key {
@@ -347,16 +1148,47 @@ impl Block {
             } else {
                 let mut flag = false;
                 if let Key::Esc = key {
-                    if inner_blocks[selected_block.unwrap()].has_selected_child() == false {
+                    if !inner_blocks[selected_block.unwrap()].has_selected_child() {
                         inner_blocks[selected_block.unwrap()].deselect();
                         *selected_block = None;
                         flag = true;
                     }
                 }
-                if flag == false {
-                    inner_blocks[selected_block.unwrap()].handle_input(key);
+                if !flag {
+                    return inner_blocks[selected_block.unwrap()].handle_input(key);
                 }
             }
         }
+
+        None
+    }
+
+    /// Validates the field of a form block left by an input, and every field when the
+    /// form is submitted, keeping the submit button enabled only while they are valid.
+    fn handle_form_event(
+        &mut self,
+        previous_selected_block: Option<usize>,
+        event: Option<BlockEvent>,
+    ) -> Option<BlockEvent> {
+        let (inner_blocks, selected_block, form) = match self.content {
+            BlockContent::ContainerBlock {
+                ref mut inner_blocks,
+                selected_block,
+                form: Some(ref mut form),
+                ..
+            } => (inner_blocks, selected_block, form),
+            _ => return event,
+        };
+
+        if let Some(index) = previous_selected_block {
+            if selected_block != Some(index) {
+                form.validate_field(inner_blocks, index);
+            }
+        }
+
+        let event = event.and_then(|event| form.handle_event(inner_blocks, event));
+        form.update_submit(inner_blocks);
+
+        event
     }
 }
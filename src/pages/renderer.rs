@@ -0,0 +1,560 @@
+use std::collections::HashMap;
+
+use tui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Borders, Cell, List, ListItem, ListState, Paragraph, Row, TableState, Widget, Wrap},
+    Frame,
+};
+
+use super::{
+    inner_block_border_style, inner_block_widget, table::truncate, Block, BlockContent, SortOrder,
+};
+
+/// Draws a whole tree of blocks, so that pages only have to build the tree.
+///
+/// Every block is framed with its title and border, colored when it is selected or
+/// hovered in its container. The inner blocks of a container are placed by its
+/// `BlockLayout`. Text which isn't kept in the blocks, like text coming from the app
+/// state, is given by the path of inner block indices leading to the block.
+#[derive(Default)]
+pub struct BlockRenderer<'a> {
+    texts: HashMap<Vec<usize>, Vec<Spans<'a>>>,
+    placeholders: HashMap<Vec<usize>, &'a str>,
+    summaries: HashMap<Vec<usize>, Vec<Spans<'a>>>,
+}
+
+impl<'a> BlockRenderer<'a> {
+    /// Shows the lines in the paragraph block at the path instead of its own text, or in
+    /// the list block at the path instead of its items, like a list laid out as a grid.
+    pub fn text(mut self, path: &[usize], lines: Vec<Spans<'a>>) -> Self {
+        self.texts.insert(path.to_vec(), lines);
+        self
+    }
+
+    /// Shows the placeholder in the input or list block at the path when it is empty.
+    pub fn placeholder(mut self, path: &[usize], placeholder: &'a str) -> Self {
+        self.placeholders.insert(path.to_vec(), placeholder);
+        self
+    }
+
+    /// Shows the lines in place of the list block at the path while it isn't selected, like
+    /// the chosen item of a collapsed selector.
+    pub fn summary(mut self, path: &[usize], lines: Vec<Spans<'a>>) -> Self {
+        self.summaries.insert(path.to_vec(), lines);
+        self
+    }
+
+    /// Draws the inner blocks of the container. The container itself isn't framed, as
+    /// it is usually the page.
+    pub fn draw<B>(&self, f: &mut Frame<B>, container: &Block, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        self.draw_inner_blocks(f, container, &mut vec![], layout_chunk);
+    }
+
+    fn draw_inner_blocks<B>(
+        &self,
+        f: &mut Frame<B>,
+        container: &Block,
+        path: &mut Vec<usize>,
+        layout_chunk: Rect,
+    ) where
+        B: Backend,
+    {
+        let (inner_blocks, layout) = match (container.get_inner_blocks(), container.layout()) {
+            (Ok(inner_blocks), Some(layout)) => (inner_blocks, layout),
+            _ => return,
+        };
+
+        let layout = layout.split(layout_chunk, inner_blocks.len(), container.selected_block());
+
+        for (index, block) in inner_blocks.iter().enumerate() {
+            path.push(index);
+            self.draw_block(f, container, index, block, path, layout[index]);
+            path.pop();
+
+            if let Some(error) = container.form().and_then(|form| form.error(index)) {
+                draw_field_error(f, error, layout[index]);
+            }
+        }
+    }
+
+    fn draw_block<B>(
+        &self,
+        f: &mut Frame<B>,
+        container: &Block,
+        index: usize,
+        block: &Block,
+        path: &mut Vec<usize>,
+        layout_chunk: Rect,
+    ) where
+        B: Backend,
+    {
+        let widget = inner_block_widget(container, index);
+        let is_selected = container.selected_block() == Some(index);
+        let placeholder = self.placeholders.get(path).copied().unwrap_or_default();
+
+        match &block.content {
+            BlockContent::ContainerBlock { .. } => {
+                let inner_chunk = widget.inner(layout_chunk);
+                f.render_widget(widget, layout_chunk);
+                self.draw_inner_blocks(f, block, path, inner_chunk);
+            }
+            BlockContent::ParagraphBlock { text } => {
+                let lines = match self.texts.get(path) {
+                    Some(lines) => lines.as_slice(),
+                    None => text.lines(),
+                };
+
+                draw_paragraph(
+                    f,
+                    block,
+                    widget,
+                    inner_block_border_style(container, index),
+                    lines,
+                    layout_chunk,
+                );
+            }
+            BlockContent::InputBlock { .. } => {
+                draw_input(f, block, widget, is_selected, placeholder, layout_chunk)
+            }
+            BlockContent::TextAreaBlock { .. } => {
+                draw_text_area(f, block, widget, is_selected, placeholder, layout_chunk)
+            }
+            BlockContent::ListBlock { .. } => {
+                let lines = match (self.summaries.get(path), self.texts.get(path)) {
+                    (Some(lines), _) if !is_selected => Some(lines),
+                    (_, Some(lines)) => Some(lines),
+                    _ => None,
+                };
+
+                match lines {
+                    Some(lines) => {
+                        f.render_widget(Paragraph::new(lines.clone()).block(widget), layout_chunk)
+                    }
+                    None => draw_list(f, block, widget, is_selected, placeholder, layout_chunk),
+                }
+            }
+            BlockContent::TableBlock { .. } => {
+                draw_table(f, block, widget, is_selected, placeholder, layout_chunk)
+            }
+            BlockContent::Button { .. } => draw_button(
+                f,
+                block,
+                inner_block_border_style(container, index),
+                layout_chunk,
+            ),
+        }
+    }
+}
+
+/// Draws a paragraph block showing the lines, wrapped and scrolled by the block. A
+/// scrollbar is drawn over the right border when the lines don't fit.
+pub fn draw_paragraph<B>(
+    f: &mut Frame<B>,
+    block: &Block,
+    widget: tui::widgets::Block,
+    border_style: Style,
+    lines: &[Spans],
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let text = match &block.content {
+        BlockContent::ParagraphBlock { text } => text,
+        _ => return,
+    };
+
+    let inner_chunk = widget.inner(layout_chunk);
+    f.render_widget(widget, layout_chunk);
+    if inner_chunk.width == 0 || inner_chunk.height == 0 {
+        return;
+    }
+
+    let (rows, scroll, total) = text.visible_rows(
+        lines,
+        inner_chunk.width as usize,
+        inner_chunk.height as usize,
+    );
+    f.render_widget(Paragraph::new(rows), inner_chunk);
+
+    if total > inner_chunk.height as usize && block.border().contains(Borders::RIGHT) {
+        let scrollbar = Scrollbar {
+            scroll,
+            rows: total,
+            style: border_style,
+        };
+        let track = Rect::new(
+            layout_chunk.right() - 1,
+            inner_chunk.y,
+            1,
+            inner_chunk.height,
+        );
+        f.render_widget(scrollbar, track);
+    }
+}
+
+/// Draws the error of a form field over the bottom border of its block, right under
+/// its text.
+pub fn draw_field_error<B>(f: &mut Frame<B>, error: &str, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    if layout_chunk.width < 3 || layout_chunk.height < 2 {
+        return;
+    }
+
+    let error_chunk = Rect::new(
+        layout_chunk.x + 1,
+        layout_chunk.bottom() - 1,
+        layout_chunk.width - 2,
+        1,
+    );
+    let error = Paragraph::new(Span::styled(
+        format!(" {} ", error),
+        Style::default().fg(Color::Red),
+    ));
+    f.render_widget(error, error_chunk);
+}
+
+/// Thumb of a scrollbar over a track one column wide, as long and as far down the track
+/// as the rows shown are in all the rows.
+struct Scrollbar {
+    scroll: usize,
+    rows: usize,
+    style: Style,
+}
+
+impl Widget for Scrollbar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let track = area.height as usize;
+        let thumb = (track * track / self.rows).clamp(1, track);
+        let last_scroll = self.rows.saturating_sub(track).max(1);
+        let top = (track - thumb) * self.scroll.min(last_scroll) / last_scroll;
+
+        for row in top..top + thumb {
+            buf.get_mut(area.x, area.y + row as u16)
+                .set_symbol("┃")
+                .set_style(self.style);
+        }
+    }
+}
+
+/// Draws an input block as a single line, scrolled to keep the cursor in view. The
+/// terminal cursor is placed at the edit position while the input is selected. The
+/// placeholder of the input, or else the given one, is shown while it is empty.
+pub fn draw_input<B>(
+    f: &mut Frame<B>,
+    block: &Block,
+    widget: tui::widgets::Block,
+    is_selected: bool,
+    placeholder: &str,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let input = match &block.content {
+        BlockContent::InputBlock { input } => input,
+        _ => return,
+    };
+
+    let inner_chunk = widget.inner(layout_chunk);
+    let (text, cursor_column) = input.visible_text(inner_chunk.width as usize);
+
+    let text = if input.text().is_empty() {
+        let placeholder = if input.placeholder.is_empty() {
+            placeholder
+        } else {
+            &input.placeholder
+        };
+
+        Spans::from(Span::styled(
+            placeholder.to_string(),
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        Spans::from(text)
+    };
+
+    f.render_widget(Paragraph::new(text).block(widget), layout_chunk);
+
+    if is_selected && inner_chunk.width > 0 && inner_chunk.height > 0 {
+        f.set_cursor(inner_chunk.x + cursor_column as u16, inner_chunk.y);
+    }
+}
+
+/// Draws a text area block, scrolled to keep the cursor in view, with a count of its
+/// chars on the last row. The terminal cursor is placed at the edit position while the
+/// text area is selected.
+pub fn draw_text_area<B>(
+    f: &mut Frame<B>,
+    block: &Block,
+    widget: tui::widgets::Block,
+    is_selected: bool,
+    placeholder: &str,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let text_area = match &block.content {
+        BlockContent::TextAreaBlock { text_area } => text_area,
+        _ => return,
+    };
+
+    let inner_chunk = widget.inner(layout_chunk);
+    f.render_widget(widget, layout_chunk);
+    if inner_chunk.width == 0 || inner_chunk.height == 0 {
+        return;
+    }
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+        .split(inner_chunk);
+
+    let (rows, (cursor_row, cursor_column)) =
+        text_area.visible_rows(layout[0].width as usize, layout[0].height as usize);
+
+    let lines = if text_area.is_empty() {
+        let placeholder = if text_area.placeholder.is_empty() {
+            placeholder
+        } else {
+            &text_area.placeholder
+        };
+
+        vec![Spans::from(Span::styled(
+            placeholder.to_string(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        rows.into_iter().map(Spans::from).collect()
+    };
+    f.render_widget(Paragraph::new(lines), layout[0]);
+
+    let counter = match text_area.max_length {
+        Some(max_length) => format!("{}/{}", text_area.len(), max_length),
+        None => text_area.len().to_string(),
+    };
+    let counter_style = match text_area.max_length {
+        Some(max_length) if text_area.len() >= max_length => Style::default().fg(Color::Red),
+        _ => Style::default().fg(Color::DarkGray),
+    };
+    f.render_widget(
+        Paragraph::new(Span::styled(counter, counter_style)).alignment(Alignment::Right),
+        layout[1],
+    );
+
+    if is_selected && layout[0].height > 0 {
+        f.set_cursor(
+            layout[0].x + cursor_column as u16,
+            layout[0].y + cursor_row as u16,
+        );
+    }
+}
+
+/// Draws a button block with its label centered, grayed out while the button is
+/// disabled. The area is kept in the button for mouse clicks.
+pub fn draw_button<B>(f: &mut Frame<B>, block: &Block, border_style: Style, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let button = match &block.content {
+        BlockContent::Button { button } => button,
+        _ => return,
+    };
+
+    button.set_area(layout_chunk);
+
+    let label_style = if button.enabled {
+        border_style.add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let paragraph = Paragraph::new(Span::styled(button.display_label(), label_style))
+        .alignment(Alignment::Center)
+        .block(
+            tui::widgets::Block::default()
+                .borders(block.border())
+                .border_style(border_style),
+        );
+    f.render_widget(paragraph, layout_chunk);
+}
+
+/// Draws a list block, highlighting the hovered item while the list is selected. The
+/// placeholder is shown in place of the list when it has no items.
+pub fn draw_list<B>(
+    f: &mut Frame<B>,
+    block: &Block,
+    widget: tui::widgets::Block,
+    is_selected: bool,
+    placeholder: &str,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let (items, hovered_item, chosen_item, marked_items, multi_select) = match &block.content {
+        BlockContent::ListBlock {
+            items,
+            hovered_item,
+            chosen_item,
+            marked_items,
+            multi_select,
+        } => (
+            items,
+            *hovered_item,
+            *chosen_item,
+            marked_items,
+            *multi_select,
+        ),
+        _ => return,
+    };
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new(placeholder.to_string())
+            .block(widget)
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, layout_chunk);
+        return;
+    }
+
+    let list_items = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let text = if multi_select {
+                format!("[{}] {}", if marked_items[i] { "x" } else { " " }, item)
+            } else {
+                item.clone()
+            };
+
+            let style = if chosen_item == Some(i) {
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            ListItem::new(Spans::from(Span::styled(text, style)))
+        })
+        .collect::<Vec<ListItem>>();
+
+    let list = List::new(list_items)
+        .block(widget)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ");
+
+    let mut list_state = ListState::default();
+    if is_selected {
+        list_state.select(Some(hovered_item));
+    }
+
+    f.render_stateful_widget(list, layout_chunk, &mut list_state);
+}
+
+/// Draws a table block with its columns fitted to the width, under a header which stays
+/// while the rows scroll. The selected row is highlighted while the table is selected,
+/// and the header of the focused column shows the sort. The placeholder is shown in
+/// place of the table when it has no rows.
+pub fn draw_table<B>(
+    f: &mut Frame<B>,
+    block: &Block,
+    widget: tui::widgets::Block,
+    is_selected: bool,
+    placeholder: &str,
+    layout_chunk: Rect,
+) where
+    B: Backend,
+{
+    let table = match &block.content {
+        BlockContent::TableBlock { table } => table,
+        _ => return,
+    };
+
+    if table.is_empty() {
+        let paragraph = Paragraph::new(placeholder.to_string())
+            .block(widget)
+            .wrap(Wrap { trim: true })
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, layout_chunk);
+        return;
+    }
+
+    let inner_chunk = widget.inner(layout_chunk);
+    let widths = table.column_widths(inner_chunk.width);
+
+    let header_cells = widths.iter().enumerate().map(|(column, width)| {
+        let title = table.header().get(column).cloned().unwrap_or_default();
+        let title = match table.sort() {
+            Some((sort_column, SortOrder::Ascending)) if sort_column == column => {
+                format!("{} ▲", title)
+            }
+            Some((sort_column, SortOrder::Descending)) if sort_column == column => {
+                format!("{} ▼", title)
+            }
+            _ => title,
+        };
+
+        let style = if is_selected && table.focused_column() == column {
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD)
+        };
+
+        Cell::from(Span::styled(truncate(&title, *width as usize), style))
+    });
+
+    let (rows, selected) = table.visible_rows(inner_chunk.height.saturating_sub(1) as usize);
+    let rows = rows.into_iter().map(|row| {
+        Row::new(
+            widths
+                .iter()
+                .enumerate()
+                .map(|(column, width)| {
+                    let cell = row.get(column).map_or("", String::as_str);
+                    Cell::from(truncate(cell, *width as usize))
+                })
+                .collect::<Vec<Cell>>(),
+        )
+    });
+
+    let constraints = widths
+        .iter()
+        .map(|width| Constraint::Length(*width))
+        .collect::<Vec<Constraint>>();
+
+    let table_widget = tui::widgets::Table::new(rows)
+        .header(Row::new(header_cells))
+        .block(widget)
+        .widths(&constraints)
+        .column_spacing(1)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut table_state = TableState::default();
+    if is_selected {
+        table_state.select(Some(selected));
+    }
+
+    f.render_stateful_widget(table_widget, layout_chunk, &mut table_state);
+}
@@ -0,0 +1,208 @@
+use chrono::{Datelike, Duration, Local};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    input::Key,
+    models::{Room, VtopData},
+    network::NetworkEvent,
+    slots::{active_slots, lab_slots, slot_timings, theory_slots, SlotTiming},
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType};
+
+const ROOM_CODE_INPUT: usize = 0;
+const ROOM_DETAILS_BLOCK: usize = 1;
+const SLOTS_BLOCK: usize = 2;
+const FREE_ROOMS_BLOCK: usize = 3;
+
+pub struct RoomInformationPage {}
+
+impl RoomInformationPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let room_code = Block::default("Room Code".to_string(), BlockType::InputBlock);
+        let room_details = Block::default("Room".to_string(), BlockType::ParagraphBlock);
+        let slots = Block::default("Slot".to_string(), BlockType::ListBlock);
+        let free_rooms = Block::default("Free Rooms".to_string(), BlockType::ListBlock);
+
+        block.append_inner_block(room_code).unwrap();
+        block.append_inner_block(room_details).unwrap();
+        block.append_inner_block(slots).unwrap();
+        block.append_inner_block(free_rooms).unwrap();
+
+        block.set_layout(
+            BlockLayout::horizontal(vec![Constraint::Percentage(55), Constraint::Percentage(45)])
+                .margin(2)
+                .nest(
+                    0,
+                    BlockLayout::vertical(vec![Constraint::Length(3), Constraint::Min(1)]),
+                )
+                .nest(
+                    1,
+                    BlockLayout::vertical(vec![
+                        Constraint::Percentage(40),
+                        Constraint::Percentage(60),
+                    ]),
+                ),
+        );
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &VtopData) -> Vec<NetworkEvent> {
+        let was_input_selected = block.selected_block() == Some(ROOM_CODE_INPUT);
+
+        block.handle_input(key);
+        RoomInformationPage::refresh(block, data);
+
+        if was_input_selected && key == Key::Enter {
+            let room_code = room_code(block);
+            if !room_code.is_empty() {
+                block.unselect_inner_block();
+                return vec![NetworkEvent::GetRoomInformation { room_code }];
+            }
+        }
+
+        vec![]
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        let now = Local::now().naive_local();
+        let now_slots = active_slots(now.weekday(), now.time());
+
+        let mut slots = vec![if now_slots.is_empty() {
+            "Now (no class hours)".to_string()
+        } else {
+            format!("Now ({})", now_slots.join(", "))
+        }];
+        slots.extend(slot_options());
+
+        if let Some(slots_block) = block.get_inner_block_mut(SLOTS_BLOCK) {
+            slots_block.set_list_items(slots);
+        }
+
+        let free_rooms = match chosen_timings(block) {
+            Some(timings) => data
+                .rooms
+                .values()
+                .filter(|room| room.is_free_during(&timings))
+                .map(|room| format!("{:<10} {}, {}", room.code, room.building(), room.floor()))
+                .collect(),
+            None => vec![],
+        };
+
+        if let Some(free_rooms_block) = block.get_inner_block_mut(FREE_ROOMS_BLOCK) {
+            free_rooms_block.set_list_items(free_rooms);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let code = room_code(block_self);
+        let rooms = if code.is_empty() {
+            vec![]
+        } else {
+            app.state
+                .data
+                .rooms
+                .values()
+                .filter(|room| room.code.starts_with(&code))
+                .collect::<Vec<&Room>>()
+        };
+
+        let room_details = if rooms.is_empty() {
+            vec![Spans::from(if app.is_loading {
+                "Loading..."
+            } else {
+                "Press Enter in the room code box to look up a room."
+            })]
+        } else {
+            rooms.into_iter().flat_map(room_lines).collect()
+        };
+
+        BlockRenderer::default()
+            .placeholder(
+                &[ROOM_CODE_INPUT],
+                "Room code like SJT502, or a building code like SJT",
+            )
+            .text(&[ROOM_DETAILS_BLOCK], room_details)
+            .placeholder(
+                &[FREE_ROOMS_BLOCK],
+                if chosen_timings(block_self).is_none() {
+                    "Choose a slot to list the looked up rooms which are free during it."
+                } else {
+                    "None of the looked up rooms are free during the slot."
+                },
+            )
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn room_code(block: &Block) -> String {
+    block
+        .get_inner_block(ROOM_CODE_INPUT)
+        .map(|input| input.input_text().trim().to_uppercase())
+        .unwrap_or_default()
+}
+
+/// Slots offered by the slot list after the "Now" entry.
+fn slot_options() -> Vec<String> {
+    theory_slots()
+        .into_iter()
+        .map(|slot| slot.to_string())
+        .chain(lab_slots())
+        .collect()
+}
+
+/// Timings of the slot chosen in the slot list. "Now" is the current minute.
+fn chosen_timings(block: &Block) -> Option<Vec<SlotTiming>> {
+    let index = block.get_inner_block(SLOTS_BLOCK)?.chosen_item()?;
+
+    if index == 0 {
+        let now = Local::now().naive_local();
+        return Some(vec![SlotTiming {
+            weekday: now.weekday(),
+            start: now.time(),
+            end: now.time() + Duration::minutes(1),
+        }]);
+    }
+
+    Some(slot_timings(slot_options().get(index - 1)?))
+}
+
+fn room_lines(room: &Room) -> Vec<Spans<'static>> {
+    let mut lines = vec![Spans::from(vec![
+        Span::styled(
+            room.code.clone(),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!("  {}, {}", room.building(), room.floor())),
+    ])];
+
+    if room.classes.is_empty() {
+        lines.push(Spans::from("  No classes scheduled."));
+    }
+
+    for class in room.classes.iter() {
+        lines.push(Spans::from(format!(
+            "  {:<10} {} - {} ({})",
+            class.slot, class.course_code, class.course_title, class.faculty
+        )));
+    }
+
+    lines.push(Spans::from(""));
+    lines
+}
@@ -0,0 +1,135 @@
+use tui::{
+    layout::Constraint,
+    style::{Color, Style},
+    text::{Span, Spans},
+};
+
+use crate::{
+    input::Key,
+    models::{Semester, VtopData},
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType};
+
+/// Height of the selector while it only shows the semester of the page.
+const COLLAPSED_HEIGHT: u16 = 3;
+/// Height of the selector when it is selected and lists the semesters.
+const EXPANDED_HEIGHT: u16 = 10;
+
+/// Returns the semester selector shown in the header of semester wise pages. Its first
+/// item follows the global semester, and choosing any other item pins that semester for
+/// the page in this tab.
+pub fn semester_selector() -> Block {
+    Block::default("Semester".to_string(), BlockType::ListBlock)
+}
+
+pub fn refresh_semester_selector(container: &mut Block, index: usize, data: &VtopData) {
+    let global_semester = match data.global_semester() {
+        Some(semester) => format!("Global semester ({})", semester.name),
+        None => "Global semester".to_string(),
+    };
+
+    let mut items = vec![global_semester];
+    items.extend(data.semesters.iter().map(|semester| semester.name.clone()));
+
+    if let Some(selector) = container.get_inner_block_mut(index) {
+        selector.set_list_items(items);
+    }
+}
+
+/// Checks if the page pins a semester instead of following the global semester.
+pub fn is_semester_pinned(container: &Block, index: usize) -> bool {
+    container
+        .get_inner_block(index)
+        .and_then(Block::chosen_item)
+        .is_some_and(|item| item > 0)
+}
+
+/// The semester shown by the page, either pinned in its selector or the global semester.
+pub fn selected_semester<'a>(
+    container: &Block,
+    index: usize,
+    data: &'a VtopData,
+) -> Option<&'a Semester> {
+    if is_semester_pinned(container, index) {
+        let item = container.get_inner_block(index)?.chosen_item()?;
+        return data.semesters.get(item - 1);
+    }
+
+    data.global_semester()
+}
+
+/// Id of the semester shown by the page, for the network events fetching its data. It is
+/// `None` until the semesters are fetched, leaving the choice to the network handler.
+pub fn selected_semester_id(container: &Block, index: usize, data: &VtopData) -> Option<String> {
+    selected_semester(container, index, data).map(|semester| semester.id.clone())
+}
+
+/// Handles the keys of the selector which change the global semester. 'g' makes the
+/// hovered semester the global semester, and the page follows it again.
+pub fn handle_semester_selector_input(
+    container: &mut Block,
+    index: usize,
+    key: Key,
+    data: &mut VtopData,
+) {
+    if container.selected_block() != Some(index) || key != Key::Char('g') {
+        return;
+    }
+
+    let selector = match container.get_inner_block_mut(index) {
+        Some(selector) => selector,
+        None => return,
+    };
+
+    match selector.hovered_item() {
+        Some(0) => data.global_semester_id = None,
+        Some(item) => {
+            data.global_semester_id = data
+                .semesters
+                .get(item - 1)
+                .map(|semester| semester.id.clone())
+        }
+        None => return,
+    }
+
+    selector.set_chosen_item(Some(0));
+}
+
+/// Layout of a semester wise page, with the selector first and the other inner blocks
+/// under it taking the constraints. The selector only lists the semesters while it is
+/// selected.
+pub fn semester_page_layout(constraints: Vec<Constraint>) -> BlockLayout {
+    let mut page_constraints = vec![Constraint::Length(COLLAPSED_HEIGHT)];
+    page_constraints.extend(constraints);
+
+    BlockLayout::vertical(page_constraints)
+        .margin(2)
+        .when_selected(0, Constraint::Length(EXPANDED_HEIGHT))
+}
+
+impl<'a> BlockRenderer<'a> {
+    /// Shows the semester of the page in the selector at the index while it is collapsed.
+    pub fn semester_selector(self, container: &Block, index: usize, data: &'a VtopData) -> Self {
+        let summary = match selected_semester(container, index, data) {
+            Some(semester) => Spans::from(vec![
+                Span::raw(semester.name.as_str()),
+                Span::styled(
+                    if is_semester_pinned(container, index) {
+                        "  (pinned to this tab, 'g' in the list makes a semester global)"
+                    } else {
+                        "  (global)"
+                    },
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+            None => Spans::from(Span::styled(
+                "Loading...",
+                Style::default().fg(Color::DarkGray),
+            )),
+        };
+
+        self.summary(&[index], vec![summary])
+            .placeholder(&[index], "Loading...")
+    }
+}
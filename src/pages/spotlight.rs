@@ -0,0 +1,98 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    models::{SpotlightItem, VtopData},
+};
+
+use super::{Block, BlockLayout, BlockRenderer, BlockType};
+
+const ITEMS_BLOCK: usize = 0;
+const DETAILS_BLOCK: usize = 1;
+
+pub struct SpotlightPage {}
+
+impl SpotlightPage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let items = Block::default("Spotlight".to_string(), BlockType::ListBlock);
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(items).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(
+            BlockLayout::vertical(vec![Constraint::Min(1), Constraint::Length(7)]).margin(2),
+        );
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        let items = data
+            .spotlight
+            .iter()
+            .map(|item| format!("{:<20.20} {}", item.category, item.title))
+            .collect();
+
+        if let Some(items_block) = block.get_inner_block_mut(ITEMS_BLOCK) {
+            items_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let details = match hovered_item(block_self, &app.state.data) {
+            Some(item) => {
+                let mut lines = vec![
+                    Spans::from(item.title.clone()),
+                    Spans::from(vec![
+                        Span::styled("Posted on: ", label_style),
+                        Span::raw(item.posted_on.clone()),
+                    ]),
+                ];
+
+                if let Some(link) = &item.link {
+                    lines.push(Spans::from(vec![
+                        Span::styled("Link: ", label_style),
+                        Span::raw(link.clone()),
+                    ]));
+                }
+
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .placeholder(
+                &[ITEMS_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "Nothing in the spotlight."
+                },
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn hovered_item<'a>(block: &Block, data: &'a VtopData) -> Option<&'a SpotlightItem> {
+    let index = block.get_inner_block(ITEMS_BLOCK)?.hovered_item()?;
+    data.spotlight.get(index)
+}
@@ -0,0 +1,276 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use crate::input::Key;
+
+/// Rows of a table block, with a selected row and a column to sort by.
+///
+/// Rows keep the order they are set in, and the table shows them through `order`, so
+/// that the selected row can be told apart from its position on screen.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    /// Indices of the rows in the order they are shown.
+    order: Vec<usize>,
+    /// Position of the selected row in `order`.
+    selected: usize,
+    /// Column sorted by with `s`, moved with Left and Right.
+    focused_column: usize,
+    sort: Option<(usize, SortOrder)>,
+    /// First row shown, kept by the renderer so that the selected row stays in view.
+    scroll: Cell<usize>,
+    /// Number of rows last shown, used to move by pages.
+    height: Cell<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Table {
+            header: vec![],
+            rows: vec![],
+            order: vec![],
+            selected: 0,
+            focused_column: 0,
+            sort: None,
+            scroll: Cell::new(0),
+            height: Cell::new(1),
+        }
+    }
+}
+
+impl Table {
+    pub fn header(&self) -> &[String] {
+        &self.header
+    }
+
+    pub fn set_header(&mut self, header: Vec<String>) {
+        self.header = header;
+        self.focused_column = self.focused_column.min(self.columns().saturating_sub(1));
+    }
+
+    /// Replaces the rows, keeping the sort and the position of the selection.
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.sort_rows();
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Index of the selected row in the rows as set, whatever their sort.
+    pub fn selected_row(&self) -> Option<usize> {
+        self.order.get(self.selected).copied()
+    }
+
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Handles the keys moving the selection and sorting, returning whether the key was
+    /// used.
+    pub fn handle_input(&mut self, key: Key) -> bool {
+        let last = self.rows.len().saturating_sub(1);
+        let page = self.height.get().max(1);
+
+        match key {
+            Key::Down => self.selected = (self.selected + 1).min(last),
+            Key::Up => self.selected = self.selected.saturating_sub(1),
+            Key::PageDown => self.selected = (self.selected + page).min(last),
+            Key::PageUp => self.selected = self.selected.saturating_sub(page),
+            Key::Home => self.selected = 0,
+            Key::End => self.selected = last,
+            Key::Right => {
+                self.focused_column =
+                    (self.focused_column + 1).min(self.columns().saturating_sub(1))
+            }
+            Key::Left => self.focused_column = self.focused_column.saturating_sub(1),
+            Key::Char('s') => self.cycle_sort(),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// The rows shown in a view `height` rows high, in order, and the position of the
+    /// selected row among them.
+    pub fn visible_rows(&self, height: usize) -> (Vec<&Vec<String>>, usize) {
+        let height = height.max(1);
+        self.height.set(height);
+
+        let mut scroll = self.scroll.get().min(self.rows.len().saturating_sub(1));
+        if self.selected < scroll {
+            scroll = self.selected;
+        } else if self.selected >= scroll + height {
+            scroll = self.selected + 1 - height;
+        }
+        self.scroll.set(scroll);
+
+        let rows = self
+            .order
+            .iter()
+            .skip(scroll)
+            .take(height)
+            .map(|&index| &self.rows[index])
+            .collect();
+
+        (rows, self.selected - scroll)
+    }
+
+    /// Widths of the columns fitting their content, with the widest columns narrowed
+    /// until the table fits `width` cells. Columns are a cell apart.
+    pub fn column_widths(&self, width: u16) -> Vec<u16> {
+        let columns = self.columns();
+        let mut widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                // Room for the sort arrow next to the title.
+                let title = self
+                    .header
+                    .get(column)
+                    .map_or(0, |title| title.chars().count() + 2);
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .fold(title, usize::max)
+            })
+            .collect();
+
+        let available = (width as usize).saturating_sub(columns.saturating_sub(1));
+        while widths.iter().sum::<usize>() > available {
+            match widths.iter_mut().max() {
+                Some(widest) if *widest > 1 => *widest -= 1,
+                _ => break,
+            }
+        }
+
+        widths.into_iter().map(|width| width as u16).collect()
+    }
+
+    fn columns(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .fold(self.header.len(), usize::max)
+    }
+
+    /// Sorts by the focused column, going from ascending to descending to the order the
+    /// rows were set in.
+    fn cycle_sort(&mut self) {
+        let selected_row = self.selected_row();
+
+        self.sort = match self.sort {
+            Some((column, SortOrder::Ascending)) if column == self.focused_column => {
+                Some((column, SortOrder::Descending))
+            }
+            Some((column, SortOrder::Descending)) if column == self.focused_column => None,
+            _ => Some((self.focused_column, SortOrder::Ascending)),
+        };
+        self.sort_rows();
+
+        // The selection follows its row.
+        if let Some(row) = selected_row {
+            self.selected = self
+                .order
+                .iter()
+                .position(|&index| index == row)
+                .unwrap_or(0);
+        }
+    }
+
+    fn sort_rows(&mut self) {
+        self.order = (0..self.rows.len()).collect();
+
+        if let Some((column, sort_order)) = self.sort {
+            let rows = &self.rows;
+            self.order.sort_by(|&a, &b| {
+                let ordering = compare_cells(
+                    rows[a].get(column).map_or("", String::as_str),
+                    rows[b].get(column).map_or("", String::as_str),
+                );
+
+                match sort_order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+    }
+}
+
+/// Compares cells as numbers when both are numbers, and else as text ignoring case.
+/// Numbers come before text, so that the order stays total in columns mixing both.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Cuts the text to `width` chars, ending it with `…` when it is cut.
+pub fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+
+    match width {
+        0 => String::new(),
+        _ => text.chars().take(width - 1).chain(['…']).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// First cell of every row, in the order shown.
+    fn first_cells(table: &Table) -> Vec<&str> {
+        table
+            .order
+            .iter()
+            .map(|&index| table.rows[index][0].as_str())
+            .collect()
+    }
+
+    #[test]
+    fn compares_numbers_before_text() {
+        assert_eq!(compare_cells("9", "10"), Ordering::Less);
+        assert_eq!(compare_cells(" 2.5", "-1"), Ordering::Greater);
+        assert_eq!(compare_cells("100", "A"), Ordering::Less);
+        assert_eq!(compare_cells("-", "3"), Ordering::Greater);
+        assert_eq!(compare_cells("abc", "ABD"), Ordering::Less);
+        assert_eq!(compare_cells("NaN", "NaN"), Ordering::Equal);
+    }
+
+    #[test]
+    fn sorts_mixed_columns_consistently() {
+        let cells = ["10", "b", "9", "", "A", "-", "2.5", "NaN"];
+        let mut table = Table::default();
+        table.set_rows(cells.iter().map(|cell| vec![cell.to_string()]).collect());
+
+        table.handle_input(Key::Char('s'));
+        assert_eq!(
+            first_cells(&table),
+            ["2.5", "9", "10", "NaN", "", "-", "A", "b"]
+        );
+
+        table.handle_input(Key::Char('s'));
+        assert_eq!(
+            first_cells(&table),
+            ["b", "A", "-", "", "NaN", "10", "9", "2.5"]
+        );
+    }
+}
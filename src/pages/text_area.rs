@@ -0,0 +1,273 @@
+use std::cell::Cell;
+
+use crate::input::Key;
+
+/// Multi-line text of a text area block, soft wrapped to the width it is drawn at.
+///
+/// Positions are in chars. Rows are the wrapped lines as drawn, and moving up and down
+/// goes by rows rather than by the lines of the text.
+pub struct TextArea {
+    text: String,
+    cursor: usize,
+    /// First row shown, kept by the renderer so that the cursor stays in view.
+    scroll: Cell<usize>,
+    /// Width and height the text was last drawn at, used to move between rows.
+    size: Cell<(usize, usize)>,
+    /// Most chars the text can have.
+    pub max_length: Option<usize>,
+    /// Shown in place of the text while it is empty.
+    pub placeholder: String,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        TextArea {
+            text: String::new(),
+            cursor: 0,
+            scroll: Cell::new(0),
+            size: Cell::new((80, 1)),
+            max_length: None,
+            placeholder: String::new(),
+        }
+    }
+}
+
+impl TextArea {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Handles the editing keys, returning whether the key was used.
+    pub fn handle_input(&mut self, key: Key) -> bool {
+        let (_, height) = self.size.get();
+
+        match key {
+            Key::Char(ch) => self.insert(&ch.to_string()),
+            Key::Enter => self.insert("\n"),
+            Key::Backspace if self.cursor > 0 => self.remove(self.cursor - 1, self.cursor),
+            Key::Delete if self.cursor < self.len() => self.remove(self.cursor, self.cursor + 1),
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            Key::Up => self.move_rows(-1),
+            Key::Down => self.move_rows(1),
+            Key::PageUp => self.move_rows(-(height.max(1) as isize)),
+            Key::PageDown => self.move_rows(height.max(1) as isize),
+            Key::Home => {
+                let rows = self.rows();
+                self.cursor = rows[self.cursor_row(&rows)].0;
+            }
+            Key::End => {
+                let rows = self.rows();
+                let (start, end) = rows[self.cursor_row(&rows)];
+                self.cursor = self.row_end_position(start, end);
+            }
+            Key::Backspace | Key::Delete => {}
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Inserts pasted text at the cursor, cut off at the max length.
+    pub fn paste(&mut self, text: &str) {
+        self.insert(&text.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+
+    /// The rows shown in a view of the given size and the row and column of the cursor
+    /// in it.
+    pub fn visible_rows(&self, width: usize, height: usize) -> (Vec<String>, (usize, usize)) {
+        self.size.set((width.max(1), height.max(1)));
+
+        let rows = self.rows();
+        let cursor_row = self.cursor_row(&rows);
+
+        let mut scroll = self.scroll.get().min(rows.len() - 1);
+        if cursor_row < scroll {
+            scroll = cursor_row;
+        } else if cursor_row >= scroll + height.max(1) {
+            scroll = cursor_row + 1 - height.max(1);
+        }
+        self.scroll.set(scroll);
+
+        let chars: Vec<char> = self.text.chars().collect();
+        let visible_rows = rows
+            .iter()
+            .skip(scroll)
+            .take(height)
+            .map(|(start, end)| chars[*start..*end].iter().collect())
+            .collect();
+
+        (
+            visible_rows,
+            (cursor_row - scroll, self.cursor - rows[cursor_row].0),
+        )
+    }
+
+    /// Start and end of every row. Lines longer than the width are broken after the last
+    /// whitespace fitting in the row, or at the width if there is none.
+    fn rows(&self) -> Vec<(usize, usize)> {
+        let (width, _) = self.size.get();
+        let chars: Vec<char> = self.text.chars().collect();
+
+        let mut rows = vec![];
+        let mut line_start = 0;
+        loop {
+            let line_end = (line_start..chars.len())
+                .find(|&index| chars[index] == '\n')
+                .unwrap_or(chars.len());
+
+            // The cursor after the last char of a full line needs a column too, so only
+            // the line holding it is broken when it fills the width.
+            let mut start = line_start;
+            while line_end - start > width || (line_end - start == width && self.cursor == line_end)
+            {
+                let limit = start + width;
+                let end = (start + 1..=limit)
+                    .rev()
+                    .find(|&index| chars[index - 1].is_whitespace())
+                    .unwrap_or(limit);
+                rows.push((start, end));
+                start = end;
+            }
+            rows.push((start, line_end));
+
+            if line_end == chars.len() {
+                return rows;
+            }
+            line_start = line_end + 1;
+        }
+    }
+
+    fn cursor_row(&self, rows: &[(usize, usize)]) -> usize {
+        rows.iter()
+            .rposition(|(start, _)| *start <= self.cursor)
+            .unwrap_or_default()
+    }
+
+    /// Last position of the cursor in a row. A row broken off its line ends where the
+    /// next one starts, so the cursor stops before its last char.
+    fn row_end_position(&self, start: usize, end: usize) -> usize {
+        let is_line_end = end == self.len() || self.text.chars().nth(end) == Some('\n');
+
+        if is_line_end || end == start {
+            end
+        } else {
+            end - 1
+        }
+    }
+
+    fn move_rows(&mut self, offset: isize) {
+        let rows = self.rows();
+        let row = self.cursor_row(&rows);
+        let column = self.cursor - rows[row].0;
+
+        let target = (row as isize + offset).clamp(0, rows.len() as isize - 1) as usize;
+        let (start, end) = rows[target];
+        self.cursor = (start + column).min(self.row_end_position(start, end));
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map(|(index, _)| index)
+            .unwrap_or(self.text.len())
+    }
+
+    fn insert(&mut self, text: &str) {
+        let room = match self.max_length {
+            Some(max_length) => max_length.saturating_sub(self.len()),
+            None => usize::MAX,
+        };
+        let text: String = text.chars().take(room).collect();
+
+        let index = self.byte_index(self.cursor);
+        self.text.insert_str(index, &text);
+        self.cursor += text.chars().count();
+    }
+
+    fn remove(&mut self, start: usize, end: usize) {
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.text.replace_range(range, "");
+        self.cursor = start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_area(text: &str, cursor: usize, width: usize) -> TextArea {
+        let text_area = TextArea {
+            text: text.to_string(),
+            cursor,
+            ..TextArea::default()
+        };
+        text_area.size.set((width, 3));
+        text_area
+    }
+
+    #[test]
+    fn fits_a_line_as_wide_as_the_view_in_one_row() {
+        assert_eq!(text_area("abcd", 0, 4).rows(), vec![(0, 4)]);
+        assert_eq!(text_area("abcd\nef", 6, 4).rows(), vec![(0, 4), (5, 7)]);
+    }
+
+    #[test]
+    fn makes_room_for_the_cursor_after_a_full_line() {
+        assert_eq!(text_area("abcd", 4, 4).rows(), vec![(0, 4), (4, 4)]);
+        assert_eq!(
+            text_area("abcd\nefgh", 4, 4).rows(),
+            vec![(0, 4), (4, 4), (5, 9)]
+        );
+    }
+
+    #[test]
+    fn breaks_long_lines_after_whitespace_or_at_the_width() {
+        assert_eq!(text_area("aaa bbb ccc", 0, 8).rows(), vec![(0, 8), (8, 11)]);
+        assert_eq!(
+            text_area("abcdefghij", 0, 4).rows(),
+            vec![(0, 4), (4, 8), (8, 10)]
+        );
+        assert_eq!(
+            text_area("ab\n\ncd", 0, 4).rows(),
+            vec![(0, 2), (3, 3), (4, 6)]
+        );
+    }
+
+    #[test]
+    fn moves_between_rows_keeping_the_column() {
+        // Rows are "hello ", "world " and "foo".
+        let mut text_area = text_area("hello world foo", 4, 6);
+
+        text_area.move_rows(1);
+        assert_eq!(text_area.cursor, 10);
+        text_area.move_rows(1);
+        assert_eq!(text_area.cursor, 15);
+        text_area.move_rows(1);
+        assert_eq!(text_area.cursor, 15);
+        text_area.move_rows(-1);
+        assert_eq!(text_area.cursor, 9);
+        text_area.move_rows(-5);
+        assert_eq!(text_area.cursor, 3);
+    }
+
+    #[test]
+    fn stops_before_the_break_of_a_wrapped_row() {
+        // Rows are "ab ", "cdef" and "g", so the cursor stops after "ab" on the first.
+        let mut text_area = text_area("ab cdefg", 6, 4);
+
+        text_area.move_rows(-1);
+        assert_eq!(text_area.cursor, 2);
+        text_area.move_rows(2);
+        assert_eq!(text_area.cursor, 8);
+    }
+}
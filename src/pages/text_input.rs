@@ -0,0 +1,329 @@
+use std::cell::Cell;
+
+use crate::{input::Key, util::INPUT_UNDO_HISTORY_LENGTH};
+
+/// Text of an input block with a cursor and undo history.
+///
+/// Positions are in chars, so that the cursor never lands inside a multi-byte char.
+#[derive(Default)]
+pub struct TextInput {
+    text: String,
+    cursor: usize,
+    /// First char shown, kept by the renderer so that the cursor stays in view.
+    scroll: Cell<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// Whether the last edit typed a char, so that typing a word is undone at once.
+    typing: bool,
+    /// Shows every char as `•`, for passwords.
+    pub masked: bool,
+    /// Shown in place of the text while it is empty.
+    pub placeholder: String,
+}
+
+struct Snapshot {
+    text: String,
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text, moving the cursor to its end. The replacement can be undone.
+    pub fn set_text(&mut self, text: String) {
+        self.save_snapshot();
+        self.cursor = text.chars().count();
+        self.text = text;
+    }
+
+    /// Inserts pasted text at the cursor, with line breaks turned into spaces.
+    pub fn paste(&mut self, text: &str) {
+        self.save_snapshot();
+        for ch in text.trim_end_matches(['\r', '\n']).chars() {
+            self.insert(if ch == '\r' || ch == '\n' { ' ' } else { ch });
+        }
+        self.typing = false;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Handles the editing keys, returning whether the key was used.
+    pub fn handle_input(&mut self, key: Key) -> bool {
+        let typing = matches!(key, Key::Char(ch) if !ch.is_whitespace());
+
+        match key {
+            Key::Char(ch) => {
+                if !typing || !self.typing {
+                    self.save_snapshot();
+                }
+                self.insert(ch);
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.save_snapshot();
+                self.remove(self.cursor - 1, self.cursor);
+            }
+            Key::Delete if self.cursor < self.len() => {
+                self.save_snapshot();
+                self.remove(self.cursor, self.cursor + 1);
+            }
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            Key::Home | Key::Ctrl('a') => self.cursor = 0,
+            Key::End | Key::Ctrl('e') => self.cursor = self.len(),
+            Key::CtrlLeft => self.cursor = self.previous_word_start(),
+            Key::CtrlRight => self.cursor = self.next_word_end(),
+            Key::Ctrl('w') if self.cursor > 0 => {
+                self.save_snapshot();
+                self.remove(self.previous_word_start(), self.cursor);
+            }
+            Key::Ctrl('u') if self.cursor > 0 => {
+                self.save_snapshot();
+                self.remove(0, self.cursor);
+            }
+            Key::Ctrl('z') => self.undo(),
+            Key::Ctrl('y') => self.redo(),
+            Key::Backspace | Key::Delete | Key::Ctrl('w') | Key::Ctrl('u') => {}
+            _ => return false,
+        }
+
+        self.typing = typing;
+        true
+    }
+
+    /// The text as shown, masked if needed, with the chars scrolled out of a view
+    /// `width` chars wide cut off. Also returns the column of the cursor in the view.
+    pub fn visible_text(&self, width: usize) -> (String, usize) {
+        let width = width.max(1);
+
+        // The cursor may sit right after the last char, so it needs a column of its own.
+        let mut scroll = self.scroll.get().min(self.len());
+        if self.cursor < scroll {
+            scroll = self.cursor;
+        } else if self.cursor >= scroll + width {
+            scroll = self.cursor + 1 - width;
+        }
+        self.scroll.set(scroll);
+
+        let text = self
+            .text
+            .chars()
+            .skip(scroll)
+            .take(width)
+            .map(|ch| if self.masked { '•' } else { ch })
+            .collect();
+
+        (text, self.cursor - scroll)
+    }
+
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map(|(index, _)| index)
+            .unwrap_or(self.text.len())
+    }
+
+    fn insert(&mut self, ch: char) {
+        let index = self.byte_index(self.cursor);
+        self.text.insert(index, ch);
+        self.cursor += 1;
+    }
+
+    /// Removes the chars from `start` up to `end`, leaving the cursor at `start`.
+    fn remove(&mut self, start: usize, end: usize) {
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.text.replace_range(range, "");
+        self.cursor = start;
+    }
+
+    fn previous_word_start(&self) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut index = self.cursor;
+
+        while index > 0 && chars[index - 1].is_whitespace() {
+            index -= 1;
+        }
+        while index > 0 && !chars[index - 1].is_whitespace() {
+            index -= 1;
+        }
+
+        index
+    }
+
+    fn next_word_end(&self) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut index = self.cursor;
+
+        while index < chars.len() && chars[index].is_whitespace() {
+            index += 1;
+        }
+        while index < chars.len() && !chars[index].is_whitespace() {
+            index += 1;
+        }
+
+        index
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            text: self.text.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    /// Saves the text before an edit. A new edit can't be redone past.
+    fn save_snapshot(&mut self) {
+        if self.undo_stack.len() == INPUT_UNDO_HISTORY_LENGTH {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(self.snapshot());
+        self.redo_stack.clear();
+    }
+
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo_stack.pop() {
+            self.redo_stack.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.redo_stack.pop() {
+            self.undo_stack.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.text = snapshot.text;
+        self.cursor = snapshot.cursor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An input holding the text with the cursor at `cursor`, and nothing to undo.
+    fn input(text: &str, cursor: usize) -> TextInput {
+        TextInput {
+            text: text.to_string(),
+            cursor,
+            ..TextInput::default()
+        }
+    }
+
+    fn type_text(input: &mut TextInput, text: &str) {
+        for ch in text.chars() {
+            input.handle_input(Key::Char(ch));
+        }
+    }
+
+    #[test]
+    fn moves_the_cursor_within_the_text() {
+        let mut input = input("abc", 0);
+
+        input.handle_input(Key::Left);
+        assert_eq!(input.cursor(), 0);
+        input.handle_input(Key::Right);
+        input.handle_input(Key::Right);
+        assert_eq!(input.cursor(), 2);
+        input.handle_input(Key::End);
+        input.handle_input(Key::Right);
+        assert_eq!(input.cursor(), 3);
+        input.handle_input(Key::Ctrl('a'));
+        assert_eq!(input.cursor(), 0);
+        input.handle_input(Key::Ctrl('e'));
+        assert_eq!(input.cursor(), 3);
+    }
+
+    #[test]
+    fn finds_word_boundaries_across_whitespace() {
+        let text = "one  two three";
+
+        assert_eq!(input(text, 14).previous_word_start(), 9);
+        assert_eq!(input(text, 9).previous_word_start(), 5);
+        assert_eq!(input(text, 7).previous_word_start(), 5);
+        assert_eq!(input(text, 5).previous_word_start(), 0);
+        assert_eq!(input(text, 0).previous_word_start(), 0);
+
+        assert_eq!(input(text, 0).next_word_end(), 3);
+        assert_eq!(input(text, 3).next_word_end(), 8);
+        assert_eq!(input(text, 6).next_word_end(), 8);
+        assert_eq!(input(text, 14).next_word_end(), 14);
+    }
+
+    #[test]
+    fn removes_ranges_and_deletes_words() {
+        let mut sentence = input("hello world", 11);
+        sentence.remove(5, 11);
+        assert_eq!((sentence.text(), sentence.cursor()), ("hello", 5));
+
+        let mut words = input("hello big world", 9);
+        words.handle_input(Key::Ctrl('w'));
+        assert_eq!((words.text(), words.cursor()), ("hello  world", 6));
+        words.handle_input(Key::Ctrl('u'));
+        assert_eq!((words.text(), words.cursor()), (" world", 0));
+        words.handle_input(Key::Delete);
+        assert_eq!((words.text(), words.cursor()), ("world", 0));
+    }
+
+    #[test]
+    fn undoes_typed_words_at_once() {
+        let mut input = input("", 0);
+        type_text(&mut input, "hello world");
+
+        input.handle_input(Key::Ctrl('z'));
+        assert_eq!(input.text(), "hello ");
+        input.handle_input(Key::Ctrl('z'));
+        assert_eq!(input.text(), "hello");
+        input.handle_input(Key::Ctrl('z'));
+        assert_eq!((input.text(), input.cursor()), ("", 0));
+
+        input.handle_input(Key::Ctrl('y'));
+        assert_eq!((input.text(), input.cursor()), ("hello", 5));
+
+        // A new edit drops what could be redone.
+        input.handle_input(Key::Backspace);
+        input.handle_input(Key::Ctrl('y'));
+        assert_eq!(input.text(), "hell");
+    }
+
+    #[test]
+    fn moving_the_cursor_ends_a_typed_word() {
+        let mut input = input("", 0);
+        type_text(&mut input, "ab");
+        input.handle_input(Key::Left);
+        type_text(&mut input, "c");
+
+        input.handle_input(Key::Ctrl('z'));
+        assert_eq!(input.text(), "ab");
+    }
+
+    #[test]
+    fn edits_multi_byte_text_by_char() {
+        let mut input = input("naïve café", 10);
+
+        input.handle_input(Key::Backspace);
+        assert_eq!((input.text(), input.cursor()), ("naïve caf", 9));
+        input.handle_input(Key::Home);
+        input.handle_input(Key::CtrlRight);
+        input.handle_input(Key::Left);
+        input.handle_input(Key::Left);
+        input.handle_input(Key::Backspace);
+        assert_eq!((input.text(), input.cursor()), ("nave caf", 2));
+        type_text(&mut input, "ï");
+        assert_eq!(input.text(), "naïve caf");
+
+        let (text, cursor) = input.visible_text(4);
+        assert_eq!((text.as_str(), cursor), ("naïv", 3));
+    }
+}
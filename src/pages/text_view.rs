@@ -0,0 +1,166 @@
+use std::cell::Cell;
+
+use tui::{
+    style::Style,
+    text::{Span, Spans},
+};
+
+use crate::input::Key;
+
+/// Styled text of a paragraph block, wrapped to the width it is drawn at and scrolled
+/// with the arrows and PageUp and PageDown.
+pub struct TextView {
+    lines: Vec<Spans<'static>>,
+    pub wrap: WrapMode,
+    /// First row shown. The renderer keeps it within the rows drawn.
+    scroll: Cell<usize>,
+    /// Number of rows and height the text was last drawn at, used to move by pages.
+    size: Cell<(usize, usize)>,
+}
+
+/// How lines longer than the width of a paragraph block are broken into rows.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Lines are cut off at the width.
+    NoWrap,
+    /// Lines are broken at the width, even inside words.
+    Char,
+    /// Lines are broken after the last whitespace fitting in the row, or at the width
+    /// for words longer than a row.
+    Word,
+}
+
+impl Default for TextView {
+    fn default() -> Self {
+        TextView {
+            lines: vec![],
+            wrap: WrapMode::Word,
+            scroll: Cell::new(0),
+            size: Cell::new((0, 1)),
+        }
+    }
+}
+
+impl TextView {
+    pub fn lines(&self) -> &[Spans<'static>] {
+        &self.lines
+    }
+
+    pub fn set_lines(&mut self, lines: Vec<Spans<'static>>) {
+        self.lines = lines;
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = text
+            .lines()
+            .map(|line| Spans::from(line.to_string()))
+            .collect();
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll.set(0);
+    }
+
+    /// Handles the scrolling keys, returning whether the key was used.
+    pub fn handle_input(&mut self, key: Key) -> bool {
+        let (rows, height) = self.size.get();
+        let last = rows.saturating_sub(height);
+        let scroll = self.scroll.get();
+
+        let scroll = match key {
+            Key::Down => (scroll + 1).min(last),
+            Key::Up => scroll.saturating_sub(1),
+            Key::PageDown => (scroll + height).min(last),
+            Key::PageUp => scroll.saturating_sub(height),
+            Key::Home => 0,
+            Key::End => last,
+            _ => return false,
+        };
+        self.scroll.set(scroll);
+
+        true
+    }
+
+    /// The rows of the lines shown in a view of the given size, with the first row shown
+    /// and the number of rows of all the lines. The lines are given rather than taken from
+    /// the view, so that pages can show text built when drawing.
+    pub fn visible_rows(
+        &self,
+        lines: &[Spans],
+        width: usize,
+        height: usize,
+    ) -> (Vec<Spans<'static>>, usize, usize) {
+        let height = height.max(1);
+        let rows = wrap_lines(lines, width.max(1), self.wrap);
+
+        let scroll = self.scroll.get().min(rows.len().saturating_sub(height));
+        self.scroll.set(scroll);
+        self.size.set((rows.len(), height));
+
+        let total = rows.len();
+        let visible_rows = rows.into_iter().skip(scroll).take(height).collect();
+
+        (visible_rows, scroll, total)
+    }
+}
+
+/// Breaks the lines into rows at most `width` chars long, keeping the style of every
+/// char.
+fn wrap_lines(lines: &[Spans], width: usize, mode: WrapMode) -> Vec<Spans<'static>> {
+    let mut rows = vec![];
+
+    for line in lines {
+        let chars: Vec<(char, Style)> = line
+            .0
+            .iter()
+            .flat_map(|span| span.content.chars().map(move |ch| (ch, span.style)))
+            .collect();
+
+        let mut start = 0;
+        while mode != WrapMode::NoWrap && chars.len() - start > width {
+            let limit = start + width;
+            let end = match mode {
+                WrapMode::Word => (start + 1..=limit)
+                    .rev()
+                    .find(|&index| chars[index - 1].0.is_whitespace())
+                    .unwrap_or(limit),
+                _ => limit,
+            };
+            rows.push(styled_row(&chars[start..end]));
+
+            // Whitespace a row was broken at isn't carried over to the next row.
+            start = end;
+            if mode == WrapMode::Word {
+                while start < chars.len() && chars[start].0 == ' ' {
+                    start += 1;
+                }
+            }
+        }
+
+        let end = chars.len().min(start + width);
+        rows.push(styled_row(&chars[start..end]));
+    }
+
+    rows
+}
+
+/// Joins the chars into spans of the same style.
+fn styled_row(chars: &[(char, Style)]) -> Spans<'static> {
+    let mut spans: Vec<Span<'static>> = vec![];
+    let mut text = String::new();
+    let mut text_style = None;
+
+    for &(ch, style) in chars {
+        if text_style.is_some_and(|text_style| text_style != style) {
+            spans.push(Span::styled(std::mem::take(&mut text), text_style.unwrap()));
+        }
+        text.push(ch);
+        text_style = Some(style);
+    }
+
+    if let Some(style) = text_style {
+        spans.push(Span::styled(text, style));
+    }
+
+    Spans::from(spans)
+}
@@ -0,0 +1,199 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    Frame,
+};
+
+use crate::{
+    app::App,
+    export::DataTable,
+    input::Key,
+    models::{TimetableEntry, VtopData},
+    network::NetworkEvent,
+    slots::campus_name,
+};
+
+use super::{
+    handle_semester_selector_input, refresh_semester_selector, selected_semester,
+    selected_semester_id, semester_page_layout, semester_selector, Block, BlockRenderer, BlockType,
+};
+
+const SEMESTER_BLOCK: usize = 0;
+const CLASSES_BLOCK: usize = 1;
+const DETAILS_BLOCK: usize = 2;
+
+pub struct TimeTablePage {}
+
+impl TimeTablePage {
+    pub fn fill_inner_blocks(block: &mut Block) {
+        let classes = Block::default("Classes".to_string(), BlockType::ListBlock);
+        let details = Block::default("Details".to_string(), BlockType::ParagraphBlock);
+
+        block.append_inner_block(semester_selector()).unwrap();
+        block.append_inner_block(classes).unwrap();
+        block.append_inner_block(details).unwrap();
+
+        block.set_layout(semester_page_layout(vec![
+            Constraint::Min(1),
+            Constraint::Length(10),
+        ]));
+    }
+
+    pub fn on_open(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        vec![NetworkEvent::GetTimetable {
+            semester_id: selected_semester_id(block, SEMESTER_BLOCK, data),
+        }]
+    }
+
+    pub fn handle_input(block: &mut Block, key: Key, data: &mut VtopData) -> Vec<NetworkEvent> {
+        let previous_semester = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+        handle_semester_selector_input(block, SEMESTER_BLOCK, key, data);
+        block.handle_input(key);
+        TimeTablePage::refresh(block, data);
+
+        if selected_semester_id(block, SEMESTER_BLOCK, data) != previous_semester {
+            return TimeTablePage::missing_data(block, data);
+        }
+
+        if block.selected_block() == Some(CLASSES_BLOCK) {
+            let semester_id = selected_semester_id(block, SEMESTER_BLOCK, data);
+
+            match key {
+                Key::Char('e') => {
+                    return vec![NetworkEvent::ExportTimetableCalendar { semester_id }]
+                }
+                Key::Char('c') => return vec![NetworkEvent::SyncCalendar { semester_id }],
+                Key::Char('x') => {
+                    return vec![NetworkEvent::ExportTable {
+                        table: DataTable::Timetable,
+                        semester_id,
+                    }]
+                }
+                _ => {}
+            }
+        }
+
+        vec![]
+    }
+
+    /// Fetches the time table of the selected semester if it isn't fetched yet.
+    pub fn missing_data(block: &Block, data: &VtopData) -> Vec<NetworkEvent> {
+        match selected_semester_id(block, SEMESTER_BLOCK, data) {
+            Some(semester_id) if !data.timetable.contains_key(&semester_id) => {
+                vec![NetworkEvent::GetTimetable {
+                    semester_id: Some(semester_id),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn refresh(block: &mut Block, data: &VtopData) {
+        refresh_semester_selector(block, SEMESTER_BLOCK, data);
+
+        let items = timetable(block, data)
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{:<9} {:<40.40} {:<14} {}",
+                    entry.course_code, entry.course_title, entry.slot, entry.venue
+                )
+            })
+            .collect();
+
+        if let Some(classes_block) = block.get_inner_block_mut(CLASSES_BLOCK) {
+            classes_block.set_list_items(items);
+        }
+    }
+
+    pub fn draw<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+    where
+        B: Backend,
+    {
+        let block_self = &app.state.tabs[app.state.selected_tab]
+            .state
+            .page_block
+            .block;
+
+        let label_style = Style::default()
+            .fg(Color::LightCyan)
+            .add_modifier(Modifier::BOLD);
+
+        let details = match hovered_entry(block_self, &app.state.data) {
+            Some(entry) => {
+                let mut lines = vec![
+                    Spans::from(vec![
+                        Span::styled("Course: ", label_style),
+                        Span::raw(format!(
+                            "{} - {} ({})",
+                            entry.course_code, entry.course_title, entry.course_type
+                        )),
+                    ]),
+                    Spans::from(vec![
+                        Span::styled("Faculty: ", label_style),
+                        Span::raw(entry.faculty.clone()),
+                    ]),
+                    Spans::from(vec![
+                        Span::styled("Class: ", label_style),
+                        Span::raw(format!("{} in {}", entry.class_id, entry.venue)),
+                    ]),
+                ];
+
+                let timings = entry.timings();
+                if timings.is_empty() {
+                    lines.push(Spans::from(format!(
+                        "  No timings for slot {} in the {} slot grid.",
+                        entry.slot,
+                        campus_name()
+                    )));
+                }
+
+                for timing in timings {
+                    lines.push(Spans::from(format!(
+                        "  {:?} {}-{}",
+                        timing.weekday,
+                        timing.start.format("%H:%M"),
+                        timing.end.format("%H:%M")
+                    )));
+                }
+
+                lines.push(Spans::from(Span::styled(
+                    "'x' exports the table, 'e' exports an iCalendar (.ics) file, 'c' syncs \
+                     the time table and the exams to CalDAV.",
+                    Style::default().fg(Color::DarkGray),
+                )));
+
+                lines
+            }
+            None => vec![],
+        };
+
+        BlockRenderer::default()
+            .semester_selector(block_self, SEMESTER_BLOCK, &app.state.data)
+            .placeholder(
+                &[CLASSES_BLOCK],
+                if app.is_loading {
+                    "Loading..."
+                } else {
+                    "No classes registered this semester."
+                },
+            )
+            .text(&[DETAILS_BLOCK], details)
+            .draw(f, block_self, layout_chunk);
+    }
+}
+
+fn timetable<'a>(block: &Block, data: &'a VtopData) -> &'a [TimetableEntry] {
+    selected_semester(block, SEMESTER_BLOCK, data)
+        .and_then(|semester| data.timetable.get(&semester.id))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn hovered_entry<'a>(block: &Block, data: &'a VtopData) -> Option<&'a TimetableEntry> {
+    let index = block.get_inner_block(CLASSES_BLOCK)?.hovered_item()?;
+    timetable(block, data).get(index)
+}
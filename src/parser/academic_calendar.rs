@@ -0,0 +1,152 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use scraper::Html;
+
+use super::{element_text, parse_date, selector};
+use crate::models::{CalendarDay, DayKind};
+
+const WEEKDAY_NAMES: [(&str, Weekday); 7] = [
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+/// Parses the months of the semester offered by the calendar's month `select`, with
+/// values like `01-JUL-2025`.
+pub fn parse_calendar_months(html: &str) -> Vec<NaiveDate> {
+    let document = Html::parse_document(html);
+    let option_selector = selector("select option");
+
+    let mut months = document
+        .select(&option_selector)
+        .filter_map(|option| parse_date(option.value().attr("value")?))
+        .filter_map(|date| date.with_day(1))
+        .collect::<Vec<NaiveDate>>();
+    months.sort();
+    months.dedup();
+
+    months
+}
+
+/// Parses a month of the calendar. Every cell of the month starts with the day of the
+/// month, followed by the events of the day.
+pub fn parse_calendar_month(html: &str, month: NaiveDate) -> Vec<CalendarDay> {
+    let document = Html::parse_document(html);
+    let cell_selector = selector("td");
+
+    document
+        .select(&cell_selector)
+        .filter_map(|cell| {
+            let text = element_text(&cell);
+            let (day, description) = text.split_once(' ').unwrap_or((&text, ""));
+
+            let date = month.with_day(day.parse().ok()?)?;
+            let description = description.trim();
+            if description.is_empty() {
+                return None;
+            }
+
+            Some(CalendarDay {
+                date,
+                kind: day_kind(description),
+                description: description.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Classifies descriptions like `Holiday - Deepavali`, `No Instructional Day`,
+/// `Instructional Day (Monday Day Order)` or `CAT - I`.
+fn day_kind(description: &str) -> DayKind {
+    let description = description.to_lowercase();
+    let words = description
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .collect::<Vec<&str>>();
+
+    if let Some((before, _)) = description.split_once("day order") {
+        // The weekday followed is the last one named before "day order".
+        if let Some(weekday) = before
+            .split(|ch: char| !ch.is_ascii_alphabetic())
+            .rev()
+            .find_map(|word| {
+                WEEKDAY_NAMES
+                    .iter()
+                    .find(|(name, _)| *name == word)
+                    .map(|(_, weekday)| *weekday)
+            })
+        {
+            return DayKind::DayOrder(weekday);
+        }
+    }
+
+    if description.contains("holiday") {
+        DayKind::Holiday
+    } else if description.contains("no instructional") || description.contains("no class") {
+        DayKind::NoInstruction
+    } else if words
+        .iter()
+        .any(|word| ["cat", "fat", "exam", "examination", "examinations"].contains(word))
+    {
+        DayKind::Exam
+    } else if description.contains("instructional") {
+        DayKind::Instructional
+    } else {
+        DayKind::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_the_months_of_the_semester() {
+        let html = r#"<select id="calDate">
+            <option value="">Select</option>
+            <option value="01-AUG-2025">August</option>
+            <option value="01-JUL-2025">July</option>
+            <option value="15-JUL-2025">July</option>
+        </select>"#;
+
+        assert_eq!(parse_calendar_months(html), vec![date(7, 1), date(8, 1)]);
+    }
+
+    #[test]
+    fn classifies_the_days_of_a_month() {
+        let html = r#"<table>
+            <tr><th>Sun</th><th>Mon</th><th>Tue</th><th>Wed</th><th>Thu</th></tr>
+            <tr><td></td>
+                <td>7 <span>Instructional Day</span></td>
+                <td>8</td>
+                <td>9 Holiday - Muharram</td>
+                <td>10 No Instructional Day</td></tr>
+            <tr><td>12 Instructional Day (Friday Day Order)</td>
+                <td>14 CAT - I</td>
+                <td>15 Independence Day Celebrations</td></tr>
+        </table>"#;
+
+        let days = parse_calendar_month(html, date(7, 1))
+            .into_iter()
+            .map(|day| (day.date, day.kind))
+            .collect::<Vec<(NaiveDate, DayKind)>>();
+
+        assert_eq!(
+            days,
+            vec![
+                (date(7, 7), DayKind::Instructional),
+                (date(7, 9), DayKind::Holiday),
+                (date(7, 10), DayKind::NoInstruction),
+                (date(7, 12), DayKind::DayOrder(Weekday::Fri)),
+                (date(7, 14), DayKind::Exam),
+                (date(7, 15), DayKind::Other),
+            ]
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use scraper::Html;
+
+use super::{absolute_url, element_text, selector, table_rows, without_serial_number};
+use crate::models::{ClassMessage, SpotlightItem};
+
+/// Parses the class messages table: Course | Faculty | Posted On | Message.
+pub fn parse_class_messages(html: &str) -> Vec<ClassMessage> {
+    let document = Html::parse_document(html);
+
+    table_rows(&document)
+        .into_iter()
+        .filter_map(|cells| {
+            let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+            let texts = without_serial_number(&texts);
+
+            if texts.len() < 4 || texts[3].is_empty() {
+                return None;
+            }
+
+            Some(ClassMessage {
+                course: texts[0].clone(),
+                faculty: texts[1].clone(),
+                posted_on: texts[2].clone(),
+                message: texts[3..].join(" "),
+            })
+        })
+        .collect()
+}
+
+/// Parses the spotlight announcements: Category | Announcement | Posted On. The
+/// announcement may link to an attachment or a page.
+pub fn parse_spotlight(html: &str) -> Vec<SpotlightItem> {
+    let document = Html::parse_document(html);
+    let link_selector = selector("a[href]");
+
+    table_rows(&document)
+        .into_iter()
+        .filter_map(|cells| {
+            let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+            if texts.len() < 2 || texts[1].is_empty() {
+                return None;
+            }
+
+            let link = cells[1]
+                .select(&link_selector)
+                .filter_map(|link| link.value().attr("href"))
+                .find(|href| !href.starts_with("javascript") && *href != "#")
+                .map(absolute_url);
+
+            Some(SpotlightItem {
+                category: texts[0].clone(),
+                title: texts[1].clone(),
+                posted_on: texts.get(2).cloned().unwrap_or_default(),
+                link,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_class_messages() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course</th><th>Faculty</th><th>Posted On</th>
+                <th>Message</th></tr>
+            <tr><td>1</td><td>CSE1001 - Problem Solving</td><td>RAVI KUMAR</td>
+                <td>12-Nov-2025</td><td>Tomorrow's class moves to
+                    SJT316.</td></tr>
+            <tr><td>2</td><td>MAT1011 - Calculus</td><td>ANITA S</td>
+                <td>10-Nov-2025</td><td></td></tr>
+        </table>"#;
+
+        let messages = parse_class_messages(html);
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].course, "CSE1001 - Problem Solving");
+        assert_eq!(messages[0].faculty, "RAVI KUMAR");
+        assert_eq!(messages[0].posted_on, "12-Nov-2025");
+        assert_eq!(messages[0].message, "Tomorrow's class moves to SJT316.");
+    }
+
+    #[test]
+    fn parses_spotlight_with_links() {
+        let html = r#"<table>
+            <tr><td>Academics</td>
+                <td><a href="javascript:void(0)">FAT</a>
+                    <a href="/vtop/downloadSpotlight/fat.pdf">schedule</a></td>
+                <td>12-Nov-2025</td></tr>
+            <tr><td>Events</td><td>Riviera registrations are open</td></tr>
+            <tr><td>Empty</td><td></td><td>01-Nov-2025</td></tr>
+        </table>"#;
+
+        let items = parse_spotlight(html);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].category, "Academics");
+        assert_eq!(items[0].title, "FAT schedule");
+        assert_eq!(items[0].posted_on, "12-Nov-2025");
+        assert_eq!(
+            items[0].link.as_deref(),
+            Some("https://vtop.vit.ac.in/vtop/downloadSpotlight/fat.pdf")
+        );
+        assert_eq!(items[1].title, "Riviera registrations are open");
+        assert_eq!(items[1].posted_on, "");
+        assert_eq!(items[1].link, None);
+    }
+}
@@ -0,0 +1,107 @@
+use scraper::Html;
+
+use super::{element_text, is_class_id, is_slot, parse_course_text, parse_number, table_rows};
+use crate::models::AttendanceEntry;
+
+/// Parses the attendance table. Every course row has a course cell
+/// (`code - title - type`), a class cell (`class id - slot - venue`), the faculty and
+/// the attended classes, total classes and attendance percentage columns.
+pub fn parse_attendance(html: &str) -> Vec<AttendanceEntry> {
+    let document = Html::parse_document(html);
+
+    table_rows(&document)
+        .into_iter()
+        .filter_map(|cells| {
+            let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+
+            let course_index = texts
+                .iter()
+                .position(|text| parse_course_text(text).is_some())?;
+            let (course_code, course_title, course_type) = parse_course_text(&texts[course_index])?;
+
+            let class_index = (course_index + 1..texts.len()).find(|&index| {
+                texts[index]
+                    .split(" - ")
+                    .any(|part| is_class_id(part.trim()))
+            })?;
+            let class_parts = texts[class_index]
+                .split(" - ")
+                .map(|part| part.trim())
+                .collect::<Vec<&str>>();
+
+            let class_id = class_parts
+                .iter()
+                .find(|part| is_class_id(part))
+                .map(|part| part.to_string())
+                .unwrap_or_default();
+            // Class ids look like slots too, so they are skipped.
+            let slot = class_parts
+                .iter()
+                .find(|part| is_slot(part) && !is_class_id(part))
+                .map(|part| part.to_string())
+                .unwrap_or_default();
+
+            let faculty = texts
+                .get(class_index + 1)
+                .map(|text| text.split(" - ").next().unwrap_or("").trim().to_string())
+                .unwrap_or_default();
+
+            let numbers = texts[class_index + 1..]
+                .iter()
+                .filter_map(|text| parse_number(text))
+                .collect::<Vec<f64>>();
+            if numbers.len() < 3 {
+                return None;
+            }
+
+            Some(AttendanceEntry {
+                class_id,
+                course_code,
+                course_title,
+                course_type,
+                slot,
+                faculty,
+                attended_classes: numbers[0] as u32,
+                total_classes: numbers[1] as u32,
+                percentage: numbers[2],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_attendance_of_every_class() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course</th><th>Class</th><th>Faculty</th>
+                <th>Attended</th><th>Total</th><th>Percentage</th></tr>
+            <tr><td>1</td><td>CSE1001 - Problem Solving - Embedded Theory</td>
+                <td>VL2025260101001 - A1+TA1 - SJT502</td><td>RAVI KUMAR - SCOPE</td>
+                <td>20</td><td>24</td><td>83%</td></tr>
+            <tr><td>2</td><td>MAT1011 - Calculus - Theory Only</td>
+                <td>VL2025260101003 - B1 - TT101</td><td>ANITA S - SAS</td>
+                <td>15</td><td>21</td><td>71%</td></tr>
+            <tr><td>3</td><td>PHY1001 - Physics - Theory Only</td>
+                <td>VL2025260101004 - C1 - MB101</td><td>Not yet started</td></tr>
+        </table>"#;
+
+        let entries = parse_attendance(html);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].class_id, "VL2025260101001");
+        assert_eq!(entries[0].course_code, "CSE1001");
+        assert_eq!(entries[0].course_title, "Problem Solving");
+        assert_eq!(entries[0].course_type, "Embedded Theory");
+        assert_eq!(entries[0].slot, "A1+TA1");
+        assert_eq!(entries[0].faculty, "RAVI KUMAR");
+        assert_eq!(entries[0].attended_classes, 20);
+        assert_eq!(entries[0].total_classes, 24);
+        assert_eq!(entries[0].percentage, 83.0);
+        assert_eq!(entries[1].course_code, "MAT1011");
+        assert_eq!(entries[1].slot, "B1");
+        assert_eq!(entries[1].percentage, 71.0);
+    }
+}
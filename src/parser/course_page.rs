@@ -0,0 +1,179 @@
+use scraper::{ElementRef, Html};
+
+use super::{
+    absolute_url, element_text, quoted_arguments, selector, table_rows, without_serial_number,
+};
+use crate::models::{Course, CourseMaterial, MaterialLink};
+
+/// Parses the registered courses of a semester. Every course row has a view button
+/// carrying the class id of the course.
+pub fn parse_courses(html: &str) -> Vec<Course> {
+    let document = Html::parse_document(html);
+    let class_id_selector = selector("[data-classid], button[onclick], a[onclick]");
+
+    table_rows(&document)
+        .into_iter()
+        .filter(|cells| cells.len() >= 5)
+        .filter_map(|cells| {
+            let class_id = cells.iter().find_map(|cell| {
+                cell.select(&class_id_selector).find_map(|element| {
+                    match element.value().attr("data-classid") {
+                        Some(class_id) => Some(class_id.to_string()),
+                        None => quoted_arguments(element.value().attr("onclick")?)
+                            .into_iter()
+                            .next(),
+                    }
+                })
+            })?;
+
+            let texts = cells
+                .iter()
+                .map(element_text)
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<String>>();
+
+            let texts = without_serial_number(&texts);
+
+            if texts.len() < 5 {
+                return None;
+            }
+
+            Some(Course {
+                class_id,
+                course_code: texts[0].clone(),
+                course_title: texts[1].clone(),
+                course_type: texts[2].clone(),
+                faculty: texts[3].clone(),
+                slot: texts[4].clone(),
+            })
+        })
+        .collect()
+}
+
+/// Parses the lecture table of a course. Rows posted for the same date and topic are
+/// grouped together into a single entry.
+pub fn parse_course_materials(html: &str) -> Vec<CourseMaterial> {
+    let document = Html::parse_document(html);
+    let mut materials: Vec<CourseMaterial> = vec![];
+
+    for cells in table_rows(&document) {
+        // Sl.No | Date | Day Order | Topic | Reference Material
+        if cells.len() < 5 {
+            continue;
+        }
+
+        let date = element_text(&cells[1]);
+        let topic = element_text(&cells[3]);
+        let links = parse_material_links(&cells[4]);
+
+        if date.is_empty() || links.is_empty() {
+            continue;
+        }
+
+        match materials
+            .iter_mut()
+            .find(|material| material.date == date && material.topic == topic)
+        {
+            Some(material) => material.links.extend(links),
+            None => materials.push(CourseMaterial { date, topic, links }),
+        }
+    }
+
+    materials
+}
+
+fn parse_material_links(cell: &ElementRef) -> Vec<MaterialLink> {
+    let link_selector = selector("a, button");
+
+    cell.select(&link_selector)
+        .filter_map(|link| {
+            let target = match link.value().attr("data-url") {
+                Some(url) => url.to_string(),
+                None => match link.value().attr("href") {
+                    Some(href) if !href.starts_with("javascript") && href != "#" => {
+                        href.to_string()
+                    }
+                    _ => quoted_arguments(link.value().attr("onclick")?)
+                        .into_iter()
+                        .next()?,
+                },
+            };
+
+            let name = match element_text(&link) {
+                name if name.is_empty() => target.rsplit('/').next().unwrap_or("").to_string(),
+                name => name,
+            };
+
+            Some(MaterialLink {
+                name,
+                url: absolute_url(&target),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_courses_with_their_class_ids() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course Code</th><th>Course Title</th><th>Type</th>
+                <th>Faculty</th><th>Slot</th><th></th></tr>
+            <tr><td>1</td><td>CSE1001</td><td>Problem Solving</td><td>ETH</td>
+                <td>RAVI KUMAR</td><td>A1+TA1</td>
+                <td><button onclick="processViewStudentCourseDetail('VL2025260101001','CSE1001')">View</button></td></tr>
+            <tr><td>2</td><td>MAT1011</td><td>Calculus</td><td>TH</td><td>ANITA S</td>
+                <td>B1</td><td><a data-classid="VL2025260101002">View</a></td></tr>
+            <tr><td colspan="7">No class id here</td></tr>
+        </table>"#;
+
+        let courses = parse_courses(html);
+
+        assert_eq!(courses.len(), 2);
+        assert_eq!(courses[0].class_id, "VL2025260101001");
+        assert_eq!(courses[0].course_code, "CSE1001");
+        assert_eq!(courses[0].course_title, "Problem Solving");
+        assert_eq!(courses[0].course_type, "ETH");
+        assert_eq!(courses[0].faculty, "RAVI KUMAR");
+        assert_eq!(courses[0].slot, "A1+TA1");
+        assert_eq!(courses[1].class_id, "VL2025260101002");
+        assert_eq!(courses[1].slot, "B1");
+    }
+
+    #[test]
+    fn groups_materials_of_the_same_lecture() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Date</th><th>Day Order</th><th>Topic</th><th>Material</th></tr>
+            <tr><td>1</td><td>07-Jul-2025</td><td>Mon</td><td>Introduction</td>
+                <td><a href="/vtop/downloadPdf/intro.pdf">Slides</a>
+                    <button onclick="downloadFile('courses/notes.pdf')"></button></td></tr>
+            <tr><td>2</td><td>07-Jul-2025</td><td>Mon</td><td>Introduction</td>
+                <td><a data-url="https://example.com/reading.pdf">Reading</a></td></tr>
+            <tr><td>3</td><td>09-Jul-2025</td><td>Wed</td><td>Loops</td><td></td></tr>
+        </table>"#;
+
+        let materials = parse_course_materials(html);
+
+        assert_eq!(materials.len(), 1);
+        assert_eq!(materials[0].date, "07-Jul-2025");
+        assert_eq!(materials[0].topic, "Introduction");
+        let links = materials[0]
+            .links
+            .iter()
+            .map(|link| (link.name.as_str(), link.url.as_str()))
+            .collect::<Vec<(&str, &str)>>();
+        assert_eq!(
+            links,
+            vec![
+                (
+                    "Slides",
+                    "https://vtop.vit.ac.in/vtop/downloadPdf/intro.pdf"
+                ),
+                ("notes.pdf", "https://vtop.vit.ac.in/vtop/courses/notes.pdf"),
+                ("Reading", "https://example.com/reading.pdf"),
+            ]
+        );
+    }
+}
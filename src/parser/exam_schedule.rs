@@ -0,0 +1,88 @@
+use scraper::Html;
+
+use super::{element_text, is_course_code, parse_date, table_rows, without_serial_number};
+use crate::models::ExamScheduleEntry;
+
+/// Parses the exam schedule. Every exam starts with a row holding only its name (like
+/// `FAT`), followed by its courses: Course Code | Course Title | Course Type | Class ID |
+/// Slot | Exam Date | Exam Session | Reporting Time | Exam Time | Venue | Seat Location |
+/// Seat No.
+pub fn parse_exam_schedule(html: &str) -> Vec<ExamScheduleEntry> {
+    let document = Html::parse_document(html);
+    let mut exam_type = String::new();
+    let mut entries = vec![];
+
+    for cells in table_rows(&document) {
+        let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+
+        if texts.len() == 1 && !texts[0].is_empty() {
+            exam_type = texts[0].to_uppercase();
+            continue;
+        }
+
+        let texts = without_serial_number(&texts);
+        if texts.len() < 12 || !is_course_code(&texts[0]) {
+            continue;
+        }
+
+        entries.push(ExamScheduleEntry {
+            exam_type: exam_type.clone(),
+            course_code: texts[0].clone(),
+            course_title: texts[1].clone(),
+            slot: texts[4].clone(),
+            date: parse_date(&texts[5]),
+            session: texts[6].clone(),
+            reporting_time: texts[7].clone(),
+            exam_time: texts[8].clone(),
+            venue: texts[9].clone(),
+            seat_location: texts[10].clone(),
+            seat_number: texts[11].clone(),
+        });
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn parses_exams_under_their_type() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course Code</th><th>Course Title</th><th>Course Type</th>
+                <th>Class ID</th><th>Slot</th><th>Exam Date</th><th>Exam Session</th>
+                <th>Reporting Time</th><th>Exam Time</th><th>Venue</th>
+                <th>Seat Location</th><th>Seat No.</th></tr>
+            <tr><td colspan="13">Cat1</td></tr>
+            <tr><td>1</td><td>CSE1001</td><td>Problem Solving</td><td>ETH</td>
+                <td>VL2025260101001</td><td>A1+TA1</td><td>15-Sep-2025</td><td>FN</td>
+                <td>09:00 AM</td><td>09:30 AM - 11:00 AM</td><td>SJT502</td>
+                <td>Row 3</td><td>42</td></tr>
+            <tr><td colspan="13">FAT</td></tr>
+            <tr><td>1</td><td>CSE1001</td><td>Problem Solving</td><td>ETH</td>
+                <td>VL2025260101001</td><td>A1+TA1</td><td>-</td><td>-</td><td>-</td>
+                <td>-</td><td>-</td><td>-</td><td>-</td></tr>
+        </table>"#;
+
+        let entries = parse_exam_schedule(html);
+
+        assert_eq!(entries.len(), 2);
+        let cat1 = &entries[0];
+        assert_eq!(cat1.exam_type, "CAT1");
+        assert_eq!(cat1.course_code, "CSE1001");
+        assert_eq!(cat1.course_title, "Problem Solving");
+        assert_eq!(cat1.slot, "A1+TA1");
+        assert_eq!(cat1.date, NaiveDate::from_ymd_opt(2025, 9, 15));
+        assert_eq!(cat1.session, "FN");
+        assert_eq!(cat1.reporting_time, "09:00 AM");
+        assert_eq!(cat1.exam_time, "09:30 AM - 11:00 AM");
+        assert_eq!(cat1.venue, "SJT502");
+        assert_eq!(cat1.seat_location, "Row 3");
+        assert_eq!(cat1.seat_number, "42");
+        assert_eq!(entries[1].exam_type, "FAT");
+        assert_eq!(entries[1].date, None);
+    }
+}
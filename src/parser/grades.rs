@@ -0,0 +1,63 @@
+use scraper::Html;
+
+use super::{element_text, is_course_code, parse_number, table_rows, without_serial_number};
+use crate::models::CourseGrade;
+
+/// Parses the semester grades table: Course Code | Course Title | Course Type | Credits |
+/// Grading Type | Grand Total | Grade.
+pub fn parse_grades(html: &str) -> Vec<CourseGrade> {
+    let document = Html::parse_document(html);
+
+    table_rows(&document)
+        .into_iter()
+        .filter_map(|cells| {
+            let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+            let texts = without_serial_number(&texts);
+
+            if texts.len() < 7 || !is_course_code(&texts[0]) {
+                return None;
+            }
+
+            Some(CourseGrade {
+                course_code: texts[0].clone(),
+                course_title: texts[1].clone(),
+                course_type: texts[2].clone(),
+                credits: parse_number(&texts[3]).unwrap_or_default(),
+                grading_type: texts[4].clone(),
+                grand_total: parse_number(&texts[texts.len() - 2]),
+                grade: texts[texts.len() - 1].to_uppercase(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_grades_of_the_semester() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course Code</th><th>Course Title</th><th>Course Type</th>
+                <th>Credits</th><th>Grading Type</th><th>Grand Total</th><th>Grade</th></tr>
+            <tr><td>1</td><td>CSE1001</td><td>Problem Solving</td><td>ETH</td><td>4</td>
+                <td>RG</td><td>87.50</td><td>a</td></tr>
+            <tr><td>2</td><td>MAT1011</td><td>Calculus</td><td>TH</td><td>3</td>
+                <td>AG</td><td>-</td><td></td></tr>
+            <tr><td colspan="8">GPA: 9.00</td></tr>
+        </table>"#;
+
+        let grades = parse_grades(html);
+
+        assert_eq!(grades.len(), 2);
+        assert_eq!(grades[0].course_code, "CSE1001");
+        assert_eq!(grades[0].course_title, "Problem Solving");
+        assert_eq!(grades[0].course_type, "ETH");
+        assert_eq!(grades[0].credits, 4.0);
+        assert_eq!(grades[0].grading_type, "RG");
+        assert_eq!(grades[0].grand_total, Some(87.5));
+        assert_eq!(grades[0].grade, "A");
+        assert_eq!(grades[1].grand_total, None);
+        assert_eq!(grades[1].grade, "");
+    }
+}
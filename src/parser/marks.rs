@@ -0,0 +1,108 @@
+use scraper::Html;
+
+use super::{element_text, is_course_code, parse_number, table_rows, without_serial_number};
+use crate::models::{CourseMarks, MarkComponent};
+
+/// Parses the marks page. Every course row (ClassNbr | Course Code | Course Title |
+/// Course Type | Course System | Faculty | Slot | Course Mode) is followed by a nested
+/// table of its components (Mark Title | Max. Mark | Weightage % | Status |
+/// Scored Mark | Weightage Mark | Class Average | Remark).
+pub fn parse_marks(html: &str) -> Vec<CourseMarks> {
+    let document = Html::parse_document(html);
+    let mut marks: Vec<CourseMarks> = vec![];
+
+    for cells in table_rows(&document) {
+        let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+        let texts = without_serial_number(&texts);
+
+        if let Some(code_index) = texts.iter().position(|text| is_course_code(text)) {
+            if texts.len() < code_index + 6 {
+                continue;
+            }
+
+            marks.push(CourseMarks {
+                class_id: if code_index > 0 {
+                    texts[code_index - 1].clone()
+                } else {
+                    String::new()
+                },
+                course_code: texts[code_index].clone(),
+                course_title: texts[code_index + 1].clone(),
+                course_type: texts[code_index + 2].clone(),
+                faculty: texts[code_index + 4].clone(),
+                slot: texts[code_index + 5].clone(),
+                components: vec![],
+            });
+        } else if texts.len() >= 7 && parse_number(&texts[1]).is_some() {
+            let course = match marks.last_mut() {
+                Some(course) => course,
+                None => continue,
+            };
+
+            course.components.push(MarkComponent {
+                title: texts[0].clone(),
+                max_mark: parse_number(&texts[1]).unwrap_or(0.0),
+                weightage: parse_number(&texts[2]).unwrap_or(0.0),
+                status: texts[3].clone(),
+                scored_mark: parse_number(&texts[4]),
+                weightage_mark: parse_number(&texts[5]),
+                class_average: texts.get(6).and_then(|text| parse_number(text)),
+            });
+        }
+    }
+
+    marks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_courses_with_their_components() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>ClassNbr</th><th>Course Code</th><th>Course Title</th>
+                <th>Course Type</th><th>Course System</th><th>Faculty</th><th>Slot</th>
+                <th>Course Mode</th></tr>
+            <tr><td>1</td><td>VL2025260101001</td><td>CSE1001</td><td>Problem Solving</td>
+                <td>Embedded Theory</td><td>CBCS</td><td>RAVI KUMAR</td><td>A1+TA1</td>
+                <td>Regular</td></tr>
+            <tr><td colspan="9"><table>
+                <tr><th>Sl.No</th><th>Mark Title</th><th>Max. Mark</th><th>Weightage %</th>
+                    <th>Status</th><th>Scored Mark</th><th>Weightage Mark</th>
+                    <th>Class Average</th><th>Remark</th></tr>
+                <tr><td>1</td><td>CAT1</td><td>50.00</td><td>15.00</td><td>Present</td>
+                    <td>38.50</td><td>11.55</td><td>31.20</td><td></td></tr>
+                <tr><td>2</td><td>CAT2</td><td>50.00</td><td>15.00</td><td></td>
+                    <td></td><td></td><td></td><td></td></tr>
+            </table></td></tr>
+            <tr><td>2</td><td>VL2025260101003</td><td>MAT1011</td><td>Calculus</td>
+                <td>Theory Only</td><td>CBCS</td><td>ANITA S</td><td>B1</td>
+                <td>Regular</td></tr>
+        </table>"#;
+
+        let marks = parse_marks(html);
+
+        assert_eq!(marks.len(), 2);
+        assert_eq!(marks[0].class_id, "VL2025260101001");
+        assert_eq!(marks[0].course_code, "CSE1001");
+        assert_eq!(marks[0].course_title, "Problem Solving");
+        assert_eq!(marks[0].course_type, "Embedded Theory");
+        assert_eq!(marks[0].faculty, "RAVI KUMAR");
+        assert_eq!(marks[0].slot, "A1+TA1");
+        assert_eq!(marks[0].components.len(), 2);
+
+        let cat1 = &marks[0].components[0];
+        assert_eq!(cat1.title, "CAT1");
+        assert_eq!(cat1.max_mark, 50.0);
+        assert_eq!(cat1.weightage, 15.0);
+        assert_eq!(cat1.status, "Present");
+        assert_eq!(cat1.scored_mark, Some(38.5));
+        assert_eq!(cat1.weightage_mark, Some(11.55));
+        assert_eq!(cat1.class_average, Some(31.2));
+        assert_eq!(marks[0].components[1].scored_mark, None);
+
+        assert_eq!(marks[1].course_code, "MAT1011");
+        assert!(marks[1].components.is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::NaiveDate;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::models::Semester;
+use crate::util::VTOP_BASE_URL;
+
+pub use academic_calendar::{parse_calendar_month, parse_calendar_months};
+pub use announcements::{parse_class_messages, parse_spotlight};
+pub use attendance::parse_attendance;
+pub use course_page::{parse_course_materials, parse_courses};
+pub use exam_schedule::parse_exam_schedule;
+pub use grades::parse_grades;
+pub use marks::parse_marks;
+pub use room::parse_room_schedules;
+pub use session::{is_login_page, parse_session_tokens};
+pub use timetable::parse_timetable;
+
+mod academic_calendar;
+mod announcements;
+mod attendance;
+mod course_page;
+mod exam_schedule;
+mod grades;
+mod marks;
+mod room;
+mod session;
+mod timetable;
+
+pub fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).expect("Invalid selector.")
+}
+
+/// Returns the text of an element with all the whitespace collapsed.
+pub fn element_text(element: &ElementRef) -> String {
+    element
+        .text()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Returns the arguments quoted inside a javascript call like `onclick="view('a', 'b')"`.
+pub fn quoted_arguments(script: &str) -> Vec<String> {
+    script
+        .split(['\'', '"'])
+        .skip(1)
+        .step_by(2)
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+/// Resolves a link found on a VTOP page against the VTOP base url.
+pub fn absolute_url(target: &str) -> String {
+    if target.starts_with("http://") || target.starts_with("https://") {
+        target.to_string()
+    } else {
+        let path = target.trim_start_matches('/');
+        format!(
+            "{}{}",
+            VTOP_BASE_URL,
+            path.strip_prefix("vtop/").unwrap_or(path)
+        )
+    }
+}
+
+/// Returns the rows of every table in the document as lists of cells. Rows without
+/// `td` cells (like header rows) are skipped. Cells of nested tables are not included
+/// in the rows of the outer table, since nested rows are returned separately.
+pub fn table_rows(document: &Html) -> Vec<Vec<ElementRef<'_>>> {
+    let row_selector = selector("tr");
+
+    document
+        .select(&row_selector)
+        .map(|row| {
+            row.child_elements()
+                .filter(|cell| cell.value().name() == "td")
+                .collect::<Vec<ElementRef>>()
+        })
+        .filter(|cells| !cells.is_empty())
+        .collect()
+}
+
+/// Drops the serial number some tables start their rows with.
+pub fn without_serial_number(texts: &[String]) -> &[String] {
+    match texts.first() {
+        Some(text) if !text.is_empty() && text.chars().all(|ch| ch.is_ascii_digit()) => &texts[1..],
+        _ => texts,
+    }
+}
+
+/// Parses numbers like `38`, `38.50` or `76%`.
+pub fn parse_number(text: &str) -> Option<f64> {
+    text.trim().trim_end_matches('%').trim().parse().ok()
+}
+
+/// Parses dates like `12-Nov-2025` or `12-11-2025`.
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    ["%d-%b-%Y", "%d-%m-%Y", "%d/%m/%Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text.trim(), format).ok())
+}
+
+/// Checks for course codes like `CSE1001` or `MAT1011L`.
+pub fn is_course_code(text: &str) -> bool {
+    let letters = text
+        .chars()
+        .take_while(|ch| ch.is_ascii_uppercase())
+        .count();
+    let digits = text
+        .chars()
+        .skip(letters)
+        .take_while(|ch| ch.is_ascii_digit())
+        .count();
+    let rest = text.chars().skip(letters + digits).collect::<String>();
+
+    (2..=4).contains(&letters)
+        && (3..=4).contains(&digits)
+        && rest.chars().all(|ch| ch.is_ascii_uppercase())
+        && rest.len() <= 1
+}
+
+/// Checks for slots like `A1`, `L31+L32` or `A1+TA1+TAA1`.
+pub fn is_slot(text: &str) -> bool {
+    !text.is_empty()
+        && text.split('+').all(|slot| {
+            let letters = slot
+                .chars()
+                .take_while(|ch| ch.is_ascii_uppercase())
+                .count();
+            letters > 0
+                && slot.len() > letters
+                && slot.chars().skip(letters).all(|ch| ch.is_ascii_digit())
+        })
+}
+
+/// Checks for class ids like `VL2023240102345`.
+pub fn is_class_id(text: &str) -> bool {
+    let letters = text
+        .chars()
+        .take_while(|ch| ch.is_ascii_uppercase())
+        .count();
+
+    letters >= 2
+        && text.len() >= letters + 8
+        && text.chars().skip(letters).all(|ch| ch.is_ascii_digit())
+}
+
+/// Splits a course cell like `CSE1001 - Problem Solving ( Embedded Theory )` or
+/// `CSE1001 - Problem Solving - Embedded Theory` into its code, title and type.
+pub fn parse_course_text(text: &str) -> Option<(String, String, String)> {
+    let (code, rest) = text.split_once(" - ")?;
+    if !is_course_code(code.trim()) {
+        return None;
+    }
+
+    let rest = rest.trim();
+    let (title, course_type) = match rest
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once('('))
+    {
+        Some((title, course_type)) => (title, course_type),
+        None => rest.rsplit_once(" - ").unwrap_or((rest, "")),
+    };
+
+    Some((
+        code.trim().to_string(),
+        title.trim().to_string(),
+        course_type.trim().to_string(),
+    ))
+}
+
+/// Parses the options of a semester `select` element.
+pub fn parse_semesters(html: &str) -> Vec<Semester> {
+    let document = Html::parse_document(html);
+    let option_selector = selector("select#semesterSubId option");
+
+    document
+        .select(&option_selector)
+        .filter_map(|option| {
+            let id = option.value().attr("value")?.trim().to_string();
+            if id.is_empty() {
+                return None;
+            }
+
+            Some(Semester {
+                id,
+                name: element_text(&option),
+            })
+        })
+        .collect()
+}
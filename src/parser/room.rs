@@ -0,0 +1,83 @@
+use scraper::Html;
+
+use super::{element_text, table_rows, without_serial_number};
+use crate::models::{Room, RoomClass};
+
+/// Parses the schedule of the rooms matching a room code search. Every row is a class
+/// held in a room: Room No | Slot | Course Code | Course Title | Faculty.
+pub fn parse_room_schedules(html: &str) -> Vec<Room> {
+    let document = Html::parse_document(html);
+    let mut rooms: Vec<Room> = vec![];
+
+    for cells in table_rows(&document) {
+        let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+
+        let texts = without_serial_number(&texts);
+
+        let is_room_code = |text: &String| {
+            !text.is_empty()
+                && text.chars().all(|ch| ch.is_ascii_alphanumeric())
+                && text.chars().any(|ch| ch.is_ascii_digit())
+        };
+
+        let code = match texts.first() {
+            Some(code) if is_room_code(code) => code.to_uppercase(),
+            _ => continue,
+        };
+
+        let room = match rooms.iter().position(|room| room.code == code) {
+            Some(index) => &mut rooms[index],
+            None => {
+                rooms.push(Room {
+                    code,
+                    classes: vec![],
+                });
+                rooms.last_mut().unwrap()
+            }
+        };
+
+        // Rooms without any class are listed with an empty or `-` slot.
+        if texts.len() >= 5 && !texts[1].is_empty() && texts[1] != "-" {
+            room.classes.push(RoomClass {
+                slot: texts[1].clone(),
+                course_code: texts[2].clone(),
+                course_title: texts[3].clone(),
+                faculty: texts[4].clone(),
+            });
+        }
+    }
+
+    rooms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_classes_by_room() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Room No</th><th>Slot</th><th>Course Code</th>
+                <th>Course Title</th><th>Faculty</th></tr>
+            <tr><td>1</td><td>SJT502</td><td>A1+TA1</td><td>CSE1001</td>
+                <td>Problem Solving</td><td>RAVI KUMAR</td></tr>
+            <tr><td>2</td><td>TT101</td><td>-</td><td></td><td></td><td></td></tr>
+            <tr><td>3</td><td>sjt502</td><td>L31+L32</td><td>CSE1001</td>
+                <td>Problem Solving Lab</td><td>ANITA S</td></tr>
+            <tr><td colspan="6">No more rooms</td></tr>
+        </table>"#;
+
+        let rooms = parse_room_schedules(html);
+
+        assert_eq!(rooms.len(), 2);
+        assert_eq!(rooms[0].code, "SJT502");
+        assert_eq!(rooms[0].classes.len(), 2);
+        assert_eq!(rooms[0].classes[0].slot, "A1+TA1");
+        assert_eq!(rooms[0].classes[0].course_code, "CSE1001");
+        assert_eq!(rooms[0].classes[0].course_title, "Problem Solving");
+        assert_eq!(rooms[0].classes[0].faculty, "RAVI KUMAR");
+        assert_eq!(rooms[0].classes[1].slot, "L31+L32");
+        assert_eq!(rooms[1].code, "TT101");
+        assert!(rooms[1].classes.is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+use scraper::Html;
+
+use super::selector;
+
+/// Parses the csrf token and the authorized id (the register number) out of the hidden
+/// inputs of a page opened with a logged in session. Pages of an expired session have
+/// neither.
+pub fn parse_session_tokens(html: &str) -> Option<(String, String)> {
+    let document = Html::parse_document(html);
+    let input_value = |name: &str| {
+        document
+            .select(&selector(&format!("input[name=\"{}\"]", name)))
+            .filter_map(|input| input.value().attr("value"))
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    Some((input_value("_csrf")?, input_value("authorizedIDX")?))
+}
+
+/// Checks for the login page, which VTOP answers requests of an expired session with
+/// instead of an error status.
+pub fn is_login_page(html: &str) -> bool {
+    let document = Html::parse_document(html);
+
+    document
+        .select(&selector(
+            "form#vtopLoginForm, input[name=\"captchaStr\"], input[type=\"password\"]",
+        ))
+        .next()
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT_PAGE: &str = r#"<html><body>
+        <form id="logoutForm">
+            <input type="hidden" name="_csrf" value=" 4f1c-a9e2 " />
+            <input type="hidden" name="authorizedIDX" value="21BCE1234" />
+        </form>
+    </body></html>"#;
+
+    const LOGIN_PAGE: &str = r#"<html><body>
+        <form id="vtopLoginForm" action="/vtop/login" method="post">
+            <input type="hidden" name="_csrf" value="b7d0-33aa" />
+            <input type="text" name="username" />
+            <input type="password" name="password" />
+            <input type="text" name="captchaStr" />
+        </form>
+    </body></html>"#;
+
+    #[test]
+    fn parses_tokens_of_a_logged_in_page() {
+        assert_eq!(
+            parse_session_tokens(CONTENT_PAGE),
+            Some(("4f1c-a9e2".to_string(), "21BCE1234".to_string()))
+        );
+        assert_eq!(parse_session_tokens(LOGIN_PAGE), None);
+    }
+
+    #[test]
+    fn tells_the_login_page_apart() {
+        assert!(is_login_page(LOGIN_PAGE));
+        assert!(!is_login_page(CONTENT_PAGE));
+        assert!(!is_login_page("<table><tr><td>CSE1001</td></tr></table>"));
+    }
+}
@@ -0,0 +1,87 @@
+use scraper::Html;
+
+use super::{element_text, is_class_id, is_slot, parse_course_text, table_rows};
+use crate::models::TimetableEntry;
+
+/// Parses the registered courses of the time table page. The columns of interest are
+/// the course (`code - title ( type )`), the class id, the slot with its venue
+/// (`A1+TA1 - SJT502`) and the faculty (`name - school`).
+pub fn parse_timetable(html: &str) -> Vec<TimetableEntry> {
+    let document = Html::parse_document(html);
+
+    table_rows(&document)
+        .into_iter()
+        .filter_map(|cells| {
+            let texts = cells.iter().map(element_text).collect::<Vec<String>>();
+
+            let course_index = texts
+                .iter()
+                .position(|text| parse_course_text(text).is_some())?;
+            let (course_code, course_title, course_type) = parse_course_text(&texts[course_index])?;
+
+            let class_id = texts[course_index..]
+                .iter()
+                .find(|text| is_class_id(text))
+                .cloned()
+                .unwrap_or_default();
+
+            // Course codes look like slots too, so the slot is looked for after the course.
+            let slot_index = (course_index + 1..texts.len()).find(|&index| {
+                texts[index]
+                    .split_once(" - ")
+                    .map(|(slot, _)| is_slot(slot.trim()))
+                    .unwrap_or(false)
+            })?;
+            let (slot, venue) = texts[slot_index].split_once(" - ")?;
+
+            let faculty = texts
+                .get(slot_index + 1)
+                .map(|text| text.split(" - ").next().unwrap_or("").trim().to_string())
+                .unwrap_or_default();
+
+            Some(TimetableEntry {
+                class_id,
+                course_code,
+                course_title,
+                course_type,
+                slot: slot.trim().to_string(),
+                venue: venue.trim().to_string(),
+                faculty,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_registered_courses() {
+        let html = r#"<table>
+            <tr><th>Sl.No</th><th>Course</th><th>Class Id</th><th>Slot - Venue</th>
+                <th>Faculty</th></tr>
+            <tr><td>1</td><td>CSE1001 - Problem Solving ( Embedded Theory )</td>
+                <td>VL2025260101001</td><td>A1+TA1 - SJT502</td>
+                <td>RAVI KUMAR - SCOPE</td></tr>
+            <tr><td>2</td><td>CSE1001 - Problem Solving ( Embedded Lab )</td>
+                <td>VL2025260101002</td><td>L31+L32 - SJT316</td>
+                <td>ANITA S - SCOPE</td></tr>
+            <tr><td colspan="5">Total Credits: 4</td></tr>
+        </table>"#;
+
+        let entries = parse_timetable(html);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].class_id, "VL2025260101001");
+        assert_eq!(entries[0].course_code, "CSE1001");
+        assert_eq!(entries[0].course_title, "Problem Solving");
+        assert_eq!(entries[0].course_type, "Embedded Theory");
+        assert_eq!(entries[0].slot, "A1+TA1");
+        assert_eq!(entries[0].venue, "SJT502");
+        assert_eq!(entries[0].faculty, "RAVI KUMAR");
+        assert_eq!(entries[1].course_type, "Embedded Lab");
+        assert_eq!(entries[1].slot, "L31+L32");
+        assert_eq!(entries[1].venue, "SJT316");
+    }
+}
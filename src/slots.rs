@@ -0,0 +1,453 @@
+use std::sync::OnceLock;
+
+use chrono::{NaiveTime, Weekday};
+use eyre::{eyre, Result};
+use serde::Deserialize;
+
+use crate::util::DEFAULT_CAMPUS;
+
+/// Start and end times of the theory hours at Vellore. Hours 6 and 12 have no regular
+/// theory slots, except the `V` slots.
+const VELLORE_THEORY_HOURS: [(&str, &str); 12] = [
+    ("08:00", "08:50"),
+    ("08:55", "09:45"),
+    ("09:50", "10:40"),
+    ("10:45", "11:35"),
+    ("11:40", "12:30"),
+    ("12:35", "13:25"),
+    ("14:00", "14:50"),
+    ("14:55", "15:45"),
+    ("15:50", "16:40"),
+    ("16:45", "17:35"),
+    ("17:40", "18:30"),
+    ("18:35", "19:25"),
+];
+
+/// Theory slot of every hour from Monday to Friday at Vellore.
+const VELLORE_THEORY_GRID: [[&str; 12]; 5] = [
+    [
+        "A1", "F1", "D1", "TB1", "TG1", "", "A2", "F2", "D2", "TB2", "TG2", "V3",
+    ],
+    [
+        "B1", "G1", "E1", "TC1", "TAA1", "", "B2", "G2", "E2", "TC2", "TAA2", "V4",
+    ],
+    [
+        "C1", "A1", "F1", "V1", "V2", "", "C2", "A2", "F2", "TD2", "TBB2", "V5",
+    ],
+    [
+        "D1", "B1", "G1", "TE1", "TCC1", "", "D2", "B2", "G2", "TE2", "TCC2", "V6",
+    ],
+    [
+        "E1", "C1", "TA1", "TF1", "TD1", "", "E2", "C2", "TA2", "TF2", "TDD2", "V7",
+    ],
+];
+
+/// Start and end times of the lab hours at Vellore. Labs `L1` to `L30` take the morning
+/// hours and `L31` to `L60` the afternoon hours, six a day from Monday to Friday.
+const VELLORE_LAB_HOURS: [(&str, &str); 12] = [
+    ("08:00", "08:50"),
+    ("08:50", "09:40"),
+    ("09:50", "10:40"),
+    ("10:40", "11:30"),
+    ("11:40", "12:30"),
+    ("12:30", "13:20"),
+    ("14:00", "14:50"),
+    ("14:50", "15:40"),
+    ("15:50", "16:40"),
+    ("16:40", "17:30"),
+    ("17:40", "18:30"),
+    ("18:30", "19:20"),
+];
+
+/// Campus with built-in slot timings. Other campuses are added with their timings in the
+/// `campus_profiles` of the config file.
+const BUILT_IN_CAMPUS: &str = "vellore";
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Slot grid used by the whole app, set once from the config at startup.
+static SLOT_GRID: OnceLock<SlotGrid> = OnceLock::new();
+
+/// Slot timings of a campus, as written in the `campus_profiles` of the config file.
+/// Times are written as `HH:MM`.
+#[derive(Clone, Deserialize)]
+pub struct CampusProfile {
+    pub name: String,
+    pub theory_hours: Vec<(String, String)>,
+    /// Theory slot of every theory hour, one row per weekday starting from Monday.
+    /// Hours without a theory slot are left empty.
+    pub theory_grid: Vec<Vec<String>>,
+    /// Lab hours of a day. Labs are numbered from `L1` through the first half of the
+    /// hours of every weekday, and then through the second half.
+    pub lab_hours: Vec<(String, String)>,
+}
+
+impl CampusProfile {
+    fn built_in(name: &str) -> Option<CampusProfile> {
+        if name != BUILT_IN_CAMPUS {
+            return None;
+        }
+
+        let hours = |hours: &[(&str, &str)]| {
+            hours
+                .iter()
+                .map(|(start, end)| (start.to_string(), end.to_string()))
+                .collect()
+        };
+
+        Some(CampusProfile {
+            name: name.to_string(),
+            theory_hours: hours(&VELLORE_THEORY_HOURS),
+            theory_grid: VELLORE_THEORY_GRID
+                .iter()
+                .map(|slots| slots.iter().map(|slot| slot.to_string()).collect())
+                .collect(),
+            lab_hours: hours(&VELLORE_LAB_HOURS),
+        })
+    }
+
+    fn grid(&self) -> Result<SlotGrid> {
+        let parse_hours = |hours: &[(String, String)]| {
+            hours
+                .iter()
+                .map(|(start, end)| Ok((parse_time(start)?, parse_time(end)?)))
+                .collect::<Result<Vec<(NaiveTime, NaiveTime)>>>()
+        };
+
+        if self.theory_grid.len() > WEEKDAYS.len() {
+            return Err(eyre!(
+                "Campus {} has more than {} days in its theory grid.",
+                self.name,
+                WEEKDAYS.len()
+            ));
+        }
+
+        if self
+            .theory_grid
+            .iter()
+            .any(|slots| slots.len() != self.theory_hours.len())
+        {
+            return Err(eyre!(
+                "Every day of the theory grid of campus {} should have {} slots.",
+                self.name,
+                self.theory_hours.len()
+            ));
+        }
+
+        if !self.lab_hours.len().is_multiple_of(2) {
+            return Err(eyre!(
+                "Campus {} should have an even number of lab hours.",
+                self.name
+            ));
+        }
+
+        Ok(SlotGrid {
+            name: self.name.clone(),
+            theory_hours: parse_hours(&self.theory_hours)?,
+            theory_grid: self
+                .theory_grid
+                .iter()
+                .map(|slots| {
+                    slots
+                        .iter()
+                        .map(|slot| slot.trim().to_uppercase())
+                        .collect()
+                })
+                .collect(),
+            lab_hours: parse_hours(&self.lab_hours)?,
+        })
+    }
+}
+
+fn parse_time(time: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(time.trim(), "%H:%M")
+        .map_err(|_| eyre!("Invalid slot time {}, expected HH:MM.", time))
+}
+
+struct SlotGrid {
+    name: String,
+    theory_hours: Vec<(NaiveTime, NaiveTime)>,
+    theory_grid: Vec<Vec<String>>,
+    lab_hours: Vec<(NaiveTime, NaiveTime)>,
+}
+
+impl SlotGrid {
+    fn slot_timings(&self, slot: &str) -> Vec<SlotTiming> {
+        let mut timings = slot
+            .split('+')
+            .map(|slot| slot.trim().to_uppercase())
+            .flat_map(|slot| self.single_slot_timings(&slot))
+            .collect::<Vec<SlotTiming>>();
+        timings.sort_by_key(|timing| (timing.weekday.num_days_from_monday(), timing.start));
+
+        let mut merged_timings: Vec<SlotTiming> = vec![];
+        for timing in timings {
+            match merged_timings.last_mut() {
+                Some(last) if last.weekday == timing.weekday && timing.start <= last.end => {
+                    last.end = last.end.max(timing.end);
+                }
+                _ => merged_timings.push(timing),
+            }
+        }
+
+        merged_timings
+    }
+
+    fn single_slot_timings(&self, slot: &str) -> Vec<SlotTiming> {
+        if let Some(number) = slot
+            .strip_prefix('L')
+            .and_then(|number| number.parse::<usize>().ok())
+        {
+            return self.lab_timing(number).into_iter().collect();
+        }
+
+        let mut timings = vec![];
+        for (day, hours) in self.theory_grid.iter().enumerate() {
+            for (hour, theory_slot) in hours.iter().enumerate() {
+                if theory_slot == slot {
+                    let (start, end) = self.theory_hours[hour];
+                    timings.push(SlotTiming {
+                        weekday: WEEKDAYS[day],
+                        start,
+                        end,
+                    });
+                }
+            }
+        }
+
+        timings
+    }
+
+    fn lab_count(&self) -> usize {
+        self.theory_grid.len() * self.lab_hours.len()
+    }
+
+    fn lab_timing(&self, number: usize) -> Option<SlotTiming> {
+        if number == 0 || number > self.lab_count() {
+            return None;
+        }
+
+        let hours_per_half = self.lab_hours.len() / 2;
+        let labs_per_half = self.theory_grid.len() * hours_per_half;
+
+        let index = number - 1;
+        let (half, index) = (index / labs_per_half, index % labs_per_half);
+        let (day, hour) = (index / hours_per_half, index % hours_per_half);
+        let (start, end) = self.lab_hours[half * hours_per_half + hour];
+
+        Some(SlotTiming {
+            weekday: WEEKDAYS[day],
+            start,
+            end,
+        })
+    }
+}
+
+/// Sets the campus whose slot timings are used by the app. Profiles from the config file
+/// take precedence over the built-in ones with the same name. Only the first call has
+/// an effect, and the default campus is used if it is never called.
+pub fn set_campus(name: &str, profiles: &[CampusProfile]) -> Result<()> {
+    let name = name.trim().to_lowercase();
+
+    let profile = profiles
+        .iter()
+        .find(|profile| profile.name.to_lowercase() == name)
+        .cloned()
+        .or_else(|| CampusProfile::built_in(&name))
+        .ok_or_else(|| {
+            eyre!(
+                "Unknown campus {}, add its slot timings to campus_profiles in the config.",
+                name
+            )
+        })?;
+
+    let _ = SLOT_GRID.set(profile.grid()?);
+    Ok(())
+}
+
+fn slot_grid() -> &'static SlotGrid {
+    SLOT_GRID.get_or_init(|| {
+        CampusProfile::built_in(DEFAULT_CAMPUS)
+            .unwrap()
+            .grid()
+            .unwrap()
+    })
+}
+
+/// Name of the campus whose slot timings are in use.
+pub fn campus_name() -> &'static str {
+    &slot_grid().name
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SlotTiming {
+    pub weekday: Weekday,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl SlotTiming {
+    pub fn contains(&self, weekday: Weekday, time: NaiveTime) -> bool {
+        self.weekday == weekday && self.start <= time && time < self.end
+    }
+
+    pub fn overlaps(&self, other: &SlotTiming) -> bool {
+        self.weekday == other.weekday && self.start < other.end && other.start < self.end
+    }
+}
+
+/// Returns the weekly timings of a slot like `A1`, `L31` or `A1+TA1`. Back to back
+/// hours on a day, like the two hours of `L31+L32`, are merged into a single timing.
+pub fn slot_timings(slot: &str) -> Vec<SlotTiming> {
+    slot_grid().slot_timings(slot)
+}
+
+/// Every theory slot in the order they first appear in the week.
+pub fn theory_slots() -> Vec<&'static str> {
+    let mut slots = vec![];
+    for hours in slot_grid().theory_grid.iter() {
+        for slot in hours.iter() {
+            if !slot.is_empty() && !slots.contains(&slot.as_str()) {
+                slots.push(slot.as_str());
+            }
+        }
+    }
+
+    slots
+}
+
+/// Every lab slot, from `L1` on.
+pub fn lab_slots() -> Vec<String> {
+    (1..=slot_grid().lab_count())
+        .map(|number| format!("L{}", number))
+        .collect()
+}
+
+/// Slots (theory and lab) running at the given time.
+pub fn active_slots(weekday: Weekday, time: NaiveTime) -> Vec<String> {
+    theory_slots()
+        .into_iter()
+        .map(|slot| slot.to_string())
+        .chain(lab_slots())
+        .filter(|slot| {
+            slot_timings(slot)
+                .iter()
+                .any(|timing| timing.contains(weekday, time))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vellore() -> SlotGrid {
+        CampusProfile::built_in(BUILT_IN_CAMPUS)
+            .unwrap()
+            .grid()
+            .unwrap()
+    }
+
+    fn timing(weekday: Weekday, start: &str, end: &str) -> SlotTiming {
+        SlotTiming {
+            weekday,
+            start: parse_time(start).unwrap(),
+            end: parse_time(end).unwrap(),
+        }
+    }
+
+    #[test]
+    fn decodes_theory_slot_on_every_day_it_runs() {
+        assert_eq!(
+            vellore().slot_timings("A1"),
+            vec![
+                timing(Weekday::Mon, "08:00", "08:50"),
+                timing(Weekday::Wed, "08:55", "09:45"),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_tutorial_slot() {
+        assert_eq!(
+            vellore().slot_timings("ta1"),
+            vec![timing(Weekday::Fri, "09:50", "10:40")]
+        );
+    }
+
+    #[test]
+    fn merges_back_to_back_lab_hours() {
+        assert_eq!(
+            vellore().slot_timings("L31+L32"),
+            vec![timing(Weekday::Mon, "14:00", "15:40")]
+        );
+    }
+
+    #[test]
+    fn decodes_combined_slot_in_weekly_order() {
+        assert_eq!(
+            vellore().slot_timings("A1+TA1"),
+            vec![
+                timing(Weekday::Mon, "08:00", "08:50"),
+                timing(Weekday::Wed, "08:55", "09:45"),
+                timing(Weekday::Fri, "09:50", "10:40"),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_slots_against_a_custom_profile() {
+        let profile = CampusProfile {
+            name: "test".to_string(),
+            theory_hours: vec![
+                ("09:00".to_string(), "10:00".to_string()),
+                ("10:00".to_string(), "11:00".to_string()),
+            ],
+            theory_grid: vec![
+                vec!["A1".to_string(), "TA1".to_string()],
+                vec!["".to_string(), "a1".to_string()],
+            ],
+            lab_hours: vec![
+                ("09:00".to_string(), "09:30".to_string()),
+                ("13:00".to_string(), "13:30".to_string()),
+            ],
+        };
+        let grid = profile.grid().unwrap();
+
+        assert_eq!(
+            grid.slot_timings("A1+TA1"),
+            vec![
+                timing(Weekday::Mon, "09:00", "11:00"),
+                timing(Weekday::Tue, "10:00", "11:00"),
+            ]
+        );
+        // Labs go through the mornings of every day before the afternoons.
+        assert_eq!(
+            grid.slot_timings("L2+L3"),
+            vec![
+                timing(Weekday::Mon, "13:00", "13:30"),
+                timing(Weekday::Tue, "09:00", "09:30"),
+            ]
+        );
+        assert!(grid.slot_timings("L5").is_empty());
+    }
+
+    #[test]
+    fn unknown_slots_have_no_timings() {
+        assert!(vellore().slot_timings("Z9").is_empty());
+        assert!(vellore().slot_timings("L61").is_empty());
+    }
+
+    #[test]
+    fn chennai_isnt_built_in() {
+        assert!(CampusProfile::built_in("chennai").is_none());
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     app::Tab,
+    models::{Change, VtopData},
     pages::{Page, PageBlock},
-    util::NOTIFICATION_HISTORY_LENGTH,
+    util::{CHANGE_HISTORY_LENGTH, NOTIFICATION_HISTORY_LENGTH},
 };
 use std::time::Instant;
 
@@ -14,7 +15,7 @@ pub struct TabState {
 
 impl Default for TabState {
     fn default() -> Self {
-        let default_page = Page::Debug;
+        let default_page = Page::Home;
         let sidebar_hover = Page::iterator()
             .position(|page| page.to_string() == default_page.to_string())
             .unwrap();
@@ -28,12 +29,14 @@ impl Default for TabState {
     }
 }
 
+#[derive(Clone)]
 pub enum Session {
     LoggedOut,
     LoggedIn {
         serverid: String,
         jsessionid: String,
         csrf_token: String,
+        authorized_id: String,
     },
 }
 
@@ -63,6 +66,9 @@ pub struct AppState {
     pub session: Session,
     pub notification_scroll: usize,
     pub notifications: Vec<Notification>,
+    pub data: VtopData,
+    /// Whether the last request failed to reach VTOP.
+    pub connection_lost: bool,
 }
 
 impl Default for AppState {
@@ -76,6 +82,8 @@ impl Default for AppState {
             session: Session::LoggedOut,
             notification_scroll: 0,
             notifications: vec![],
+            data: VtopData::default(),
+            connection_lost: false,
         }
     }
 }
@@ -88,6 +96,12 @@ impl AppState {
         }
     }
 
+    /// Whether the last request failed to reach VTOP, leaving only the cached data to
+    /// show.
+    pub fn is_offline(&self) -> bool {
+        self.connection_lost
+    }
+
     pub fn dispatch_notification(&mut self, text: String) {
         if self.notifications.len() == NOTIFICATION_HISTORY_LENGTH {
             self.notifications.remove(0);
@@ -95,4 +109,19 @@ impl AppState {
         self.notifications.push(Notification::new(text));
         self.notification_scroll = 0;
     }
+
+    /// Shows the changes as notifications and keeps them for the home page.
+    pub fn record_changes(&mut self, changes: Vec<Change>) {
+        for change in changes {
+            self.dispatch_notification(change.kind.to_string());
+            self.data.changes.push(change);
+        }
+
+        let excess = self
+            .data
+            .changes
+            .len()
+            .saturating_sub(CHANGE_HISTORY_LENGTH);
+        self.data.changes.drain(..excess);
+    }
 }
@@ -0,0 +1,107 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process, thread,
+    time::Duration,
+};
+
+use eyre::{eyre, Result};
+
+use crate::{
+    models::VtopData,
+    util::{CACHE_FILE_NAME, PROJECT_DIRECTORY_NAME},
+};
+
+/// Path of the cache of the data fetched from VTOP, in the vtop-cli cache directory.
+pub fn path() -> Option<PathBuf> {
+    Some(
+        dirs::cache_dir()?
+            .join(PROJECT_DIRECTORY_NAME)
+            .join(CACHE_FILE_NAME),
+    )
+}
+
+/// Reads the cached data, falling back to no data if nothing is cached yet.
+pub fn load() -> Result<VtopData> {
+    let path = match path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok(VtopData::default()),
+    };
+
+    let contents = fs::read_to_string(&path)?;
+    serde_json::from_str(&contents)
+        .map_err(|e| eyre!("Ignoring the invalid cache {}: {}", path.display(), e))
+}
+
+/// Replaces the cache with the data. The data is written to a temporary file of this
+/// process first, so a crash midway leaves the previous cache intact, and the cache is
+/// locked meanwhile, so that other runs of vtop-cli don't save over it at the same time.
+pub fn save(data: &VtopData) -> Result<()> {
+    let path = path().ok_or_else(|| eyre!("No cache directory found to save the data in."))?;
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let _lock = CacheLock::acquire(&path)?;
+
+    let temporary_path = path.with_extension(format!("{}.tmp", process::id()));
+    let written = fs::write(&temporary_path, serde_json::to_string(data)?)
+        .and_then(|()| fs::rename(&temporary_path, &path));
+    if written.is_err() {
+        let _ = fs::remove_file(&temporary_path);
+    }
+
+    Ok(written?)
+}
+
+/// Lock file held while the cache is saved, removed when dropped.
+struct CacheLock {
+    path: PathBuf,
+}
+
+impl CacheLock {
+    /// How long to wait for another save to let go of the cache.
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+    /// Age after which a lock is taken to be left behind by a crashed run.
+    const STALE_AGE: Duration = Duration::from_secs(60);
+
+    fn acquire(cache_path: &Path) -> Result<CacheLock> {
+        let path = cache_path.with_extension("lock");
+        let mut waited = Duration::ZERO;
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(CacheLock { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    let is_stale = fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .is_some_and(|age| age > Self::STALE_AGE);
+                    if is_stale {
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+
+                    if waited >= Self::TIMEOUT {
+                        return Err(eyre!(
+                            "The cache is locked by another run of vtop-cli, remove {} if none is running.",
+                            path.display()
+                        ));
+                    }
+                    thread::sleep(Self::RETRY_INTERVAL);
+                    waited += Self::RETRY_INTERVAL;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for CacheLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
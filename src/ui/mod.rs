@@ -80,6 +80,17 @@ where
         ));
     }
 
+    if app.state.is_offline() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            " offline ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let tabs_text = Spans::from(spans);
 
     let tabs = Paragraph::new(tabs_text)
@@ -196,7 +207,7 @@ where
             .add_modifier(Modifier::BOLD),
     ));
 
-    let footer_paragraph = if app.state.notifications.len() == 0
+    let footer_paragraph = if app.state.notifications.is_empty()
         || app.state.notifications[app.state.notifications.len() - 1]
             .origin_time
             .elapsed()
@@ -236,7 +247,7 @@ where
                     .skip(app.state.notification_scroll - text.len())
                     .collect::<String>());
             } else {
-                footer_text += &seperator;
+                footer_text += seperator;
             }
 
             footer_text += &text;
@@ -1,4 +1,36 @@
 pub const MAXIMUM_TABS: usize = 10;
 pub const NOTIFICATION_HISTORY_LENGTH: usize = 10;
+pub const CHANGE_HISTORY_LENGTH: usize = 50;
+pub const INPUT_UNDO_HISTORY_LENGTH: usize = 100;
 pub const NOTIFICATION_SEPERATOR: &str = " || ";
-pub const NOTIFICATION_TIMEOUT_SECS: usize = 5;
\ No newline at end of file
+pub const NOTIFICATION_TIMEOUT_SECS: usize = 5;
+pub const VTOP_BASE_URL: &str = "https://vtop.vit.ac.in/vtop/";
+/// Environment variable holding the cookie of a VTOP session logged in from a browser.
+pub const VTOP_COOKIE_VARIABLE: &str = "VTOP_COOKIE";
+pub const PROJECT_DIRECTORY_NAME: &str = "vtop-cli";
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+pub const CACHE_FILE_NAME: &str = "data.json";
+pub const DOWNLOADS_DIRECTORY_NAME: &str = "vtop-cli";
+pub const MAXIMUM_CONCURRENT_DOWNLOADS: usize = 3;
+pub const DOWNLOAD_PROGRESS_INTERVAL_MILLIS: u128 = 250;
+pub const ATTENDANCE_THRESHOLD: f64 = 75.0;
+pub const DEFAULT_CAMPUS: &str = "vellore";
+pub const VTOP_TIMEZONE: &str = "Asia/Kolkata";
+pub const VTOP_UTC_OFFSET_SECS: i32 = 5 * 3600 + 30 * 60;
+pub const DEFAULT_WATCH_INTERVAL_MINUTES: u64 = 15;
+pub const MAXIMUM_WATCH_BACKOFF: u32 = 16;
+pub const WATCH_JITTER: f64 = 0.1;
+
+/// Replaces characters that are not allowed in file or directory names.
+pub fn sanitize_file_name(name: &str) -> String {
+    let sanitized = name
+        .chars()
+        .map(|ch| match ch {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            ch if ch.is_control() => '_',
+            ch => ch,
+        })
+        .collect::<String>();
+
+    sanitized.trim().trim_end_matches('.').to_string()
+}
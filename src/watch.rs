@@ -0,0 +1,175 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use chrono::Local;
+use clap::ValueEnum;
+use eyre::{eyre, Result};
+
+use crate::{
+    alerts,
+    app::App,
+    cli::{self, WatchArgs},
+    models::Change,
+    network::{NetworkEvent, NetworkHandler},
+    util::{MAXIMUM_WATCH_BACKOFF, WATCH_JITTER},
+};
+
+/// Pages refreshed by `vtop-cli watch`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum WatchPage {
+    Attendance,
+    Marks,
+    Grades,
+    Messages,
+    Spotlight,
+}
+
+impl WatchPage {
+    fn network_event(&self, semester_id: &str) -> NetworkEvent {
+        let semester_id = Some(semester_id.to_string());
+
+        match self {
+            WatchPage::Attendance => NetworkEvent::GetAttendance { semester_id },
+            WatchPage::Marks => NetworkEvent::GetMarks { semester_id },
+            WatchPage::Grades => NetworkEvent::GetGrades { semester_id },
+            WatchPage::Messages => NetworkEvent::GetClassMessages,
+            WatchPage::Spotlight => NetworkEvent::GetSpotlight,
+        }
+    }
+}
+
+/// Refreshes the pages every interval until interrupted, reporting the changes found in
+/// each round. A round in which any page fails doubles the wait before the next one, up
+/// to `MAXIMUM_WATCH_BACKOFF` times the interval.
+pub async fn run(
+    network_handler: &mut NetworkHandler<'_>,
+    app: &Arc<tokio::sync::Mutex<App>>,
+    args: WatchArgs,
+) -> Result<()> {
+    if args.interval == 0 {
+        return Err(eyre!("The interval must be at least a minute."));
+    }
+
+    let semester_id = cli::resolve_semester(network_handler, app, &args.semester).await?;
+    let pages = if args.pages.is_empty() {
+        WatchPage::value_variants().to_vec()
+    } else {
+        args.pages.clone()
+    };
+    let interval = Duration::from_secs(args.interval * 60);
+
+    eprintln!(
+        "Watching {} every {} minutes. Press Ctrl+C to stop.",
+        pages
+            .iter()
+            .map(|page| format!("{:?}", page).to_lowercase())
+            .collect::<Vec<String>>()
+            .join(", "),
+        args.interval
+    );
+
+    let mut backoff = 1;
+    loop {
+        let round_started_at = Local::now();
+        let mut failed = false;
+
+        for page in pages.iter() {
+            if let Err(e) = network_handler
+                .run_network_event(page.network_event(&semester_id))
+                .await
+            {
+                eprintln!("{} Failed to refresh {:?}: {}", timestamp(), page, e);
+                failed = true;
+            }
+        }
+
+        let changes = take_round_output(app, round_started_at).await;
+        for change in changes.iter() {
+            report(change, &args).await;
+        }
+
+        backoff = if failed {
+            (backoff * 2).min(MAXIMUM_WATCH_BACKOFF)
+        } else {
+            1
+        };
+
+        tokio::select! {
+            _ = tokio::time::sleep(with_jitter(interval * backoff)) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+/// Changes detected since the round started. Other notifications of the round, like
+/// same day exam alerts, are printed to stderr, as the change notifications are
+/// reported separately.
+async fn take_round_output(
+    app: &Arc<tokio::sync::Mutex<App>>,
+    round_started_at: chrono::DateTime<Local>,
+) -> Vec<Change> {
+    let mut app = app.lock().await;
+
+    let changes: Vec<Change> = app
+        .state
+        .data
+        .changes
+        .iter()
+        .filter(|change| change.detected_at >= round_started_at)
+        .cloned()
+        .collect();
+    let change_texts: HashSet<String> = changes
+        .iter()
+        .map(|change| change.kind.to_string())
+        .collect();
+
+    for notification in app.state.notifications.drain(..) {
+        if !change_texts.contains(&notification.text) {
+            eprintln!("{} {}", timestamp(), notification.text);
+        }
+    }
+
+    changes
+}
+
+/// Prints the change, appends it to the log file and runs the exec hook with the change
+/// as JSON on stdin. Failures of the log and the hook are printed without stopping the
+/// watch.
+async fn report(change: &Change, args: &WatchArgs) {
+    println!(
+        "{} {}",
+        change.detected_at.format("%Y-%m-%d %H:%M:%S"),
+        change.kind
+    );
+
+    if let Some(path) = &args.log {
+        let line = format!(
+            "{} {}",
+            change.detected_at.format("%Y-%m-%d %H:%M:%S"),
+            change.kind
+        );
+        if let Err(e) = alerts::append_line(path, &line) {
+            eprintln!(
+                "{} Failed to write to {}: {}",
+                timestamp(),
+                path.display(),
+                e
+            );
+        }
+    }
+
+    if let Some(command) = &args.exec {
+        if let Err(e) = alerts::run_hook(command, change).await {
+            eprintln!("{} Failed to run {}: {}", timestamp(), command, e);
+        }
+    }
+}
+
+/// Spreads the wait by up to `WATCH_JITTER` of it either way, so that many watchers
+/// don't hit VTOP at the same moment.
+fn with_jitter(duration: Duration) -> Duration {
+    duration.mul_f64(1.0 + WATCH_JITTER * (fastrand::f64() * 2.0 - 1.0))
+}
+
+fn timestamp() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}